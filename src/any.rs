@@ -128,6 +128,7 @@ impl<'de> Visitor<'de> for AnyVisitor {
 ///  - `bool` to `Box<bool>`
 ///  - `u8`/`u16`/`u32`/`u64`/`u128` to `Box<u8>`/`Box<u16>`/`Box<u32>`/`Box<u64>`/`Box<u128>`
 ///  - `str` and `String` to `Box<String>`
+///  - UTCTime and GeneralizedTime to `Box<String>` in the ISO 8601 form `YYYY-MM-DDTHH:MM:SS[.F]Z`
 ///  - `&[u8]` and `Vec<u8>` to `Box<Vec<u8>>`
 ///  - `None` and `()` to `Box<()>`
 ///  - `Some(T)` to `Box<T>` where `T` is mapped according to this list
@@ -163,6 +164,7 @@ impl Serialize for Box<dyn AnyObject> {
     where
         S: Serializer,
     {
-        erased_serde::serialize(self.serializable(), serializer)
+        // Call `serializable` on the inner object, since the box itself implements `AnyObject` too
+        erased_serde::serialize(self.as_ref().serializable(), serializer)
     }
 }
@@ -0,0 +1,90 @@
+use crate::{misc, validate::DEPTH_LIMIT, Result, SerdeAsn1DerError};
+use asn1_der::{der, ErrorChain};
+use std::mem;
+
+/// The length byte of an indefinite length
+const INDEFINITE_LENGTH: u8 = 0x80;
+/// An end-of-contents marker, which terminates the value of an object with indefinite length
+const EOC: [u8; 2] = [0x00, 0x00];
+
+/// Reads the next byte at `pos` and advances `pos`
+fn read(bytes: &[u8], pos: &mut usize) -> Result<u8> {
+    let byte = *bytes.get(*pos).ok_or(eio!("The BER object is truncated"))?;
+    *pos += 1;
+    Ok(byte)
+}
+
+/// Reads the remaining bytes of a definite length that starts with `first`
+///
+/// _Note: Unlike DER, BER allows long-form lengths with leading zero bytes or values below 128_
+fn read_length(first: u8, bytes: &[u8], pos: &mut usize) -> Result<usize> {
+    match first {
+        len if len < 0x80 => return Ok(len as usize),
+        0xff => Err(einval!("The length byte 0xff is reserved"))?,
+        size if (size & 0x7f) as usize > mem::size_of::<usize>() => {
+            Err(eunsupported!("The object length is greater than `usize::max_value()`"))?
+        }
+        _ => (),
+    }
+
+    let mut len = 0;
+    for _ in 0..first & 0x7f {
+        len = len << 8 | read(bytes, pos)? as usize;
+    }
+    Ok(len)
+}
+
+/// Converts the BER object at `pos` into DER, appends it to `der` and advances `pos` past the object
+fn convert(bytes: &[u8], pos: &mut usize, der: &mut Vec<u8>, depth: usize) -> Result<()> {
+    let tag = read(bytes, pos)?;
+    misc::check_single_byte_tag(tag)?;
+    let constructed = tag & 0x20 != 0;
+    if constructed && depth >= DEPTH_LIMIT {
+        return Err(SerdeAsn1DerError::DepthLimitExceeded);
+    }
+
+    // Convert the value
+    let mut value = Vec::new();
+    match read(bytes, pos)? {
+        INDEFINITE_LENGTH if !constructed => Err(einval!("A primitive object must not have an indefinite length"))?,
+        INDEFINITE_LENGTH => {
+            // Convert the subobjects up to the end-of-contents marker
+            while bytes.get(*pos..*pos + EOC.len()) != Some(&EOC) {
+                convert(bytes, pos, &mut value, depth + 1)?;
+            }
+            *pos += EOC.len();
+        }
+        first => {
+            let len = read_length(first, bytes, pos)?;
+            let end =
+                (pos.checked_add(len).filter(|end| *end <= bytes.len())).ok_or(eio!("The BER object is truncated"))?;
+            match constructed {
+                // Convert the subobjects since they may have indefinite lengths too
+                true => {
+                    while *pos < end {
+                        convert(&bytes[..end], pos, &mut value, depth + 1)?;
+                    }
+                }
+                false => value.extend_from_slice(&bytes[*pos..end]),
+            }
+            *pos = end;
+        }
+    }
+
+    // Write the object with a minimal definite length
+    der.push(tag);
+    der::length::encode(value.len(), der).propagate(e!("Failed to write length"))?;
+    der.extend_from_slice(&value);
+    Ok(())
+}
+
+/// Converts the first BER object in `bytes` into DER by replacing indefinite lengths with definite ones and
+/// re-encoding all lengths in their minimal form
+///
+/// _Note: Other BER features like constructed strings or non-canonical booleans are passed through as they are and
+/// rejected during deserialization_
+pub(crate) fn to_der(bytes: &[u8]) -> Result<Vec<u8>> {
+    let mut der = Vec::new();
+    convert(bytes, &mut 0, &mut der, 0)?;
+    Ok(der)
+}
@@ -0,0 +1,40 @@
+//! A `#[serde(with = "serde_asn1_der::bool_default_false")]`-helper for ASN.1-`BOOLEAN DEFAULT FALSE` (e.g. X.509's
+//! `BasicConstraints.cA`)
+//!
+//! `false` is omitted during serialization as required by DER, and an absent element is read as `false`:
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! #[derive(Serialize, Deserialize)]
+//! struct BasicConstraints {
+//!     #[serde(with = "serde_asn1_der::bool_default_false")]
+//!     ca: bool
+//! }
+//! ```
+//!
+//! _Note: Like `Option`s, the element can only be absent if it is the last element of the sequence_
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+/// Serializes `true` as BOOLEAN and omits `false`
+pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        true => serializer.serialize_some(&true),
+        false => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes an absent element as `false`
+///
+/// _Note: An explicitly encoded `false` is rejected since DER forbids encoding the default value_
+pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<bool>::deserialize(deserializer)? {
+        Some(false) => Err(D::Error::custom("The default value FALSE must not be encoded")),
+        value => Ok(value.is_some()),
+    }
+}
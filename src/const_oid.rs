@@ -0,0 +1,35 @@
+//! A `#[serde(with = "serde_asn1_der::const_oid")]`-helper to map a `const_oid::ObjectIdentifier` to an OBJECT
+//! IDENTIFIER
+//!
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! #[derive(Serialize, Deserialize)]
+//! struct AlgorithmIdentifier {
+//!     #[serde(with = "serde_asn1_der::const_oid")]
+//!     algorithm: const_oid::ObjectIdentifier,
+//!     parameters: ()
+//! }
+//! ```
+
+use crate::ObjectIdentifier;
+use ::const_oid::ObjectIdentifier as ConstOid;
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes `oid` as OBJECT IDENTIFIER
+pub fn serialize<S>(oid: &ConstOid, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ObjectIdentifier::from_bytes(oid.as_bytes()).map_err(S::Error::custom)?.serialize(serializer)
+}
+
+/// Deserializes an OBJECT IDENTIFIER
+///
+/// _Note: `const_oid::ObjectIdentifier` has a limited capacity, so very long object identifiers are rejected_
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ConstOid, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let oid = ObjectIdentifier::deserialize(deserializer)?;
+    ConstOid::from_bytes(oid.as_bytes()).map_err(D::Error::custom)
+}
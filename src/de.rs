@@ -1,18 +1,241 @@
-use crate::{misc::ReaderSource, Result, SerdeAsn1DerError};
+#[cfg(feature = "more_types")]
+use crate::types::{self, Special};
+use crate::{
+    ber,
+    misc::{
+        self, BufReadSource, ReaderSource, IA5_STRING_TAG, NUMERIC_STRING_TAG, PRINTABLE_STRING_TAG, VISIBLE_STRING_TAG,
+    },
+    real,
+    validate::DEPTH_LIMIT,
+    Result, SerdeAsn1DerError,
+};
 use asn1_der::{
+    der,
     typed::{Boolean, DerDecodable, DerTypeView, Integer, Null, OctetString, Sequence, Utf8String},
-    DerObject, ErrorChain, Sink, Source,
+    Asn1DerError, Asn1DerErrorVariant, DerObject, ErrorChain, Sink, Source, VecBacking,
 };
+#[cfg(feature = "more_types")]
+use serde::de::value::BorrowedBytesDeserializer;
+#[cfg(feature = "map")]
+use serde::de::MapAccess;
 use serde::{
-    de::{DeserializeSeed, SeqAccess, Visitor},
-    Deserialize,
+    de::{
+        value::U32Deserializer, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, SeqAccess,
+        VariantAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserialize,
+};
+use std::{
+    cell::Cell,
+    convert::TryFrom,
+    io::{BufRead, Read},
+    marker::PhantomData,
 };
-use std::io::Read;
+
+/// The deserializer options
+#[derive(Debug, Clone, Copy)]
+struct Options {
+    /// Whether sequence elements that are not consumed by the target type are skipped or rejected
+    allow_trailing_seq_elements: bool,
+    /// Whether strings can be read from NumericString-, PrintableString-, IA5String- and VisibleString-objects too
+    accept_any_string_tag: bool,
+    /// Whether errors within sequences are wrapped into `AtPath` with the path to the failing element
+    track_path: bool,
+    /// Whether booleans can be read from INTEGER-objects with the value `0` or `1` too
+    bool_from_integer: bool,
+    /// Whether any non-zero BOOLEAN value is read as `true` as in BER
+    lenient_bool: bool,
+}
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            allow_trailing_seq_elements: true,
+            accept_any_string_tag: false,
+            track_path: false,
+            bool_from_integer: false,
+            lenient_bool: false,
+        }
+    }
+}
+
+/// The tag of an end-of-contents marker
+const EOC_TAG: u8 = 0x00;
+/// The default maximum length of an object that is read from a source (64 MiB)
+const MAX_LENGTH: usize = 64 * 1024 * 1024;
+
+/// Copies the next object from `source` into `backing` if its declared length does not exceed `max_length`
+///
+/// _Note: The length is checked before the value is read, so a forged length cannot exhaust the memory_
+fn read_object<'a>(
+    mut source: impl Source,
+    mut backing: impl Sink + Into<&'a [u8]>,
+    max_length: usize,
+) -> Result<DerObject<'a>> {
+    let tag = source.read().propagate(e!("Failed to read tag"))?;
+    misc::check_single_byte_tag(tag)?;
+    let len =
+        der::length::decode(&mut source).propagate(e!("Failed to decode length"))?.ok_or(eio!("Truncated length"))?;
+    if len > max_length {
+        return Err(SerdeAsn1DerError::LengthLimitExceeded);
+    }
+
+    // Copy the object
+    backing.write(tag).propagate(e!("Failed to write tag"))?;
+    der::length::encode(len, &mut backing).propagate(e!("Failed to write length"))?;
+    for _ in 0..len {
+        backing
+            .write(source.read().propagate(e!("Failed to read object value"))?)
+            .propagate(e!("Failed to write byte"))?;
+    }
+    Ok(DerObject::decode(backing.into()).propagate(e!("Failed to decode DER object"))?)
+}
+
+/// The tag that is expected for an object with the universal `tag` if `implicit_tag` replaces it
+///
+/// _Note: The implicit tag takes the constructed bit of `tag`_
+fn expected_tag(implicit_tag: Option<u8>, tag: u8) -> u8 {
+    implicit_tag.map(|implicit| implicit | (tag & 0x20)).unwrap_or(tag)
+}
+
+/// Rejects end-of-contents markers, which must not appear in DER
+fn reject_eoc(object: &DerObject) -> Result<()> {
+    match object.tag() {
+        EOC_TAG => Err(SerdeAsn1DerError::UnexpectedEoc),
+        _ => Ok(()),
+    }
+}
+
+/// The tag of an UTCTime
+const UTC_TIME_TAG: u8 = 0x17;
+/// The tag of a GeneralizedTime
+const GENERALIZED_TIME_TAG: u8 = 0x18;
+
+/// Converts the DER-encoded UTCTime or GeneralizedTime `value` into the ISO 8601 form `YYYY-MM-DDTHH:MM:SS[.F]Z`
+fn time_to_iso(tag: u8, value: &[u8]) -> Result<String> {
+    // Expand the two-digit year of UTCTime according to RFC 5280
+    let century: &[u8] = match (tag, value) {
+        (UTC_TIME_TAG, [b'5'..=b'9', ..]) => b"19",
+        (UTC_TIME_TAG, _) => b"20",
+        _ => b"",
+    };
+    let time = [century, value].concat();
+
+    // Validate the format `YYYYMMDDHHMMSS[.F]Z`
+    let (datetime, fraction) = match time.strip_suffix(b"Z") {
+        Some(time) if time.len() >= 14 => time.split_at(14),
+        _ => Err(einval!("Invalid time object"))?,
+    };
+    let fraction_valid = match fraction {
+        [] => true,
+        [b'.', digits @ ..] => tag == GENERALIZED_TIME_TAG && digits.iter().all(u8::is_ascii_digit),
+        _ => false,
+    };
+    if !datetime.iter().all(u8::is_ascii_digit) || !fraction_valid {
+        Err(einval!("Invalid time object"))?;
+    }
+
+    // #implicit_validation: All bytes are ASCII
+    let time: String = time.iter().map(|b| *b as char).collect();
+    Ok(format!(
+        "{}-{}-{}T{}:{}:{}{}Z",
+        &time[0..4],
+        &time[4..6],
+        &time[6..8],
+        &time[8..10],
+        &time[10..12],
+        &time[12..14],
+        &time[14..time.len() - 1]
+    ))
+}
+
+/// Prepends the field name from `names` or the index of the `count`th element to the path of `e` if enabled
+fn at_path(
+    e: SerdeAsn1DerError,
+    names: Option<&'static [&'static str]>,
+    count: usize,
+    options: Options,
+) -> SerdeAsn1DerError {
+    match options.track_path {
+        true => match names.and_then(|names| names.get(count - 1)) {
+            Some(name) => e.at(name),
+            None => e.at(&(count - 1).to_string()),
+        },
+        false => e,
+    }
+}
 
 /// A sequence walker
 struct SequenceReader<'a> {
-    sequence: Sequence<'a>,
+    value: &'a [u8],
     pos: usize,
+    /// The amount of fields of the target struct or tuple or `None` for variable-length sequences
+    fields: Option<usize>,
+    /// The field names of the target struct or `None` if the target is not a struct
+    names: Option<&'static [&'static str]>,
+    /// The amount of elements requested so far
+    index: usize,
+    /// The nesting depth of the elements
+    depth: usize,
+    options: Options,
+}
+impl<'a> SequenceReader<'a> {
+    /// Creates a new sequence walker over the sequence `value` and validates that `value` consists of valid DER
+    /// objects
+    fn new(
+        value: &'a [u8],
+        fields: Option<usize>,
+        names: Option<&'static [&'static str]>,
+        depth: usize,
+        options: Options,
+    ) -> Result<Self> {
+        let mut pos = 0;
+        while pos < value.len() {
+            if let Some(len) = misc::short_object_len(value, pos) {
+                pos += len;
+                continue;
+            }
+            let object = misc::decode_object_at(value, pos).propagate(e!("Invalid subobject in sequence"))?;
+            reject_eoc(&object)?;
+            pos += object.raw().len();
+        }
+        Ok(Self { value, pos: 0, fields, names, index: 0, depth, options })
+    }
+
+    /// Ensures that all elements have been consumed unless trailing elements are allowed
+    ///
+    /// _Note: Since tuples have a fixed amount of elements, trailing elements are always rejected for them_
+    fn finish(self) -> Result<()> {
+        let is_tuple = self.fields.is_some() && self.names.is_none();
+        match self.pos < self.value.len() {
+            true if is_tuple || !self.options.allow_trailing_seq_elements => {
+                Err(einval!("The sequence contains more elements than expected"))?
+            }
+            _ => Ok(()),
+        }
+    }
+}
+impl<'a> SequenceReader<'a> {
+    /// Deserializes the next element with `seed`
+    fn read_next<T: DeserializeSeed<'a>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        // Past the end of a struct or tuple, `Option`s are read as absent OPTIONALs; any other field is reported as
+        //  missing, so that `serde` can apply `#[serde(default)]` to it
+        self.index += 1;
+        if self.pos >= self.value.len() {
+            return match self.fields {
+                Some(fields) if self.index <= fields => Ok(seed.deserialize(AbsentDeserializer).ok()),
+                _ => Ok(None),
+            };
+        }
+        let object = misc::decode_object_at(self.value, self.pos).propagate(e!("Failed to load subobject"))?;
+        self.pos += object.raw().len();
+
+        let mut deserializer = Deserializer::new(object, self.depth, self.options);
+        seed.deserialize(&mut deserializer).map(Some)
+    }
+    /// Prepends the field name or element index of the last element to the path of `e` if enabled
+    fn at_path(&self, e: SerdeAsn1DerError) -> SerdeAsn1DerError {
+        at_path(e, self.names, self.index, self.options)
+    }
 }
 impl<'a> SeqAccess<'a> for SequenceReader<'a> {
     type Error = SerdeAsn1DerError;
@@ -21,25 +244,680 @@ impl<'a> SeqAccess<'a> for SequenceReader<'a> {
     where
         T: DeserializeSeed<'a>,
     {
-        // Load the next object
-        let object = match self.sequence.get(self.pos) {
-            Ok(object) => object,
-            Err(_) => return Ok(None),
+        self.read_next(seed).map_err(|e| self.at_path(e))
+    }
+    fn next_element<T>(&mut self) -> Result<Option<T>>
+    where
+        T: Deserialize<'a>,
+    {
+        // Since the type is known here, an OPTIONAL that expects another tag than the element is read as absent, so
+        //  that the element is left for the next field
+        if let (Some(_), Some(&tag)) = (self.fields, self.value.get(self.pos)) {
+            if let Probe { optional: true, claims: false } = Probe::of::<T>(tag, self.options) {
+                self.index += 1;
+                return T::deserialize(AbsentDeserializer).map(Some).map_err(|e| self.at_path(e));
+            }
+        }
+        self.next_element_seed(PhantomData)
+    }
+}
+
+/// A SET walker that assigns each element to the field that expects its tag
+#[cfg(feature = "more_types")]
+struct SetReader<'a> {
+    /// The elements that have not been assigned to a field yet
+    elements: Vec<DerObject<'a>>,
+    /// The amount of fields of the target struct or tuple
+    fields: usize,
+    /// The field names of the target struct or `None` if the target is a tuple
+    names: Option<&'static [&'static str]>,
+    /// The amount of fields requested so far
+    index: usize,
+    /// The nesting depth of the elements
+    depth: usize,
+    options: Options,
+}
+#[cfg(feature = "more_types")]
+impl<'a> SetReader<'a> {
+    /// Creates a new SET walker over the elements of the SET `value`
+    fn new(
+        value: &'a [u8],
+        fields: usize,
+        names: Option<&'static [&'static str]>,
+        depth: usize,
+        options: Options,
+    ) -> Result<Self> {
+        let elements = types::subobjects(value)?;
+        elements.iter().try_for_each(reject_eoc)?;
+        Ok(Self { elements, fields, names, index: 0, depth, options })
+    }
+
+    /// Ensures that all elements have been assigned unless trailing elements are allowed
+    ///
+    /// _Note: Since tuples have a fixed amount of elements, unassigned elements are always rejected for them_
+    fn finish(self) -> Result<()> {
+        match self.elements.is_empty() {
+            false if self.names.is_none() || !self.options.allow_trailing_seq_elements => {
+                Err(einval!("The set contains more elements than expected"))?
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Deserializes the element at `position` with `seed` or reads an absent element if `position` is `None`
+    fn read<T: DeserializeSeed<'a>>(&mut self, position: Option<usize>, seed: T) -> Result<Option<T::Value>> {
+        self.index += 1;
+        let object = match position {
+            Some(position) => self.elements.remove(position),
+            None if self.index <= self.fields => return Ok(seed.deserialize(AbsentDeserializer).ok()),
+            None => return Ok(None),
         };
-        self.pos += 1;
+        seed.deserialize(&mut Deserializer::new(object, self.depth, self.options)).map(Some)
+    }
+}
+#[cfg(feature = "more_types")]
+impl<'a> SeqAccess<'a> for SetReader<'a> {
+    type Error = SerdeAsn1DerError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>>
+    where
+        T: DeserializeSeed<'a>,
+    {
+        // Without the type of the field, the elements are assigned in wire order
+        let position = (!self.elements.is_empty()).then_some(0);
+        self.read(position, seed).map_err(|e| at_path(e, self.names, self.index, self.options))
+    }
+    fn next_element<T>(&mut self) -> Result<Option<T>>
+    where
+        T: Deserialize<'a>,
+    {
+        let position = self.elements.iter().position(|element| Probe::of::<T>(element.tag(), self.options).claims);
+        self.read(position, PhantomData).map_err(|e| at_path(e, self.names, self.index, self.options))
+    }
+}
+
+/// A map walker over a SEQUENCE of two-element SEQUENCEs `{ key, value }`
+#[cfg(feature = "map")]
+struct MapReader<'a> {
+    entries: &'a [u8],
+    pos: usize,
+    /// The value of the entry whose key has been read
+    value: Option<DerObject<'a>>,
+    /// The nesting depth of the entries
+    depth: usize,
+    options: Options,
+}
+#[cfg(feature = "map")]
+impl<'a> MapAccess<'a> for MapReader<'a> {
+    type Error = SerdeAsn1DerError;
+
+    fn next_key_seed<K: DeserializeSeed<'a>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.pos >= self.entries.len() {
+            return Ok(None);
+        }
+
+        // Load the entry and split it into key and value
+        let entry = misc::decode_object_at(self.entries, self.pos).propagate(e!("Failed to load map entry"))?;
+        self.pos += entry.raw().len();
+        if entry.tag() != Sequence::TAG {
+            Err(einval!("DER object is not a valid map entry"))?;
+        }
+        let key = misc::decode_object_at(entry.value(), 0).propagate(e!("Failed to load map key"))?;
+        if key.raw().len() == entry.value().len() {
+            Err(einval!("The map entry has no value"))?;
+        }
+        let value = misc::decode_object_at(entry.value(), key.raw().len()).propagate(e!("Failed to load map value"))?;
+        if key.raw().len() + value.raw().len() != entry.value().len() {
+            Err(einval!("The map entry contains more than two elements"))?;
+        }
+        reject_eoc(&key)?;
+        reject_eoc(&value)?;
+
+        self.value = Some(value);
+        let depth = Deserializer::new(entry, self.depth, self.options).inner_depth()?;
+        seed.deserialize(&mut Deserializer::new(key, depth, self.options)).map(Some)
+    }
+    fn next_value_seed<V: DeserializeSeed<'a>>(&mut self, seed: V) -> Result<V::Value> {
+        let value = self.value.take().ok_or(eother!("The map key has not been read"))?;
+        // #implicit_validation: The depth has been checked for the key
+        seed.deserialize(&mut Deserializer::new(value, self.depth + 1, self.options))
+    }
+}
+
+/// A deserializer for an element past the end of a sequence, which only yields `None` to `Option`s
+struct AbsentDeserializer;
+impl<'de> serde::Deserializer<'de> for AbsentDeserializer {
+    type Error = SerdeAsn1DerError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        Err(einval!("The sequence contains no more elements"))?
+    }
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_none()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        newtype_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// The tag check of a type for the tag of an object
+#[derive(Debug, Clone, Copy)]
+struct Probe {
+    /// Whether the type is an `Option`
+    optional: bool,
+    /// Whether the type expects the tag (types that accept various tags claim any tag)
+    claims: bool,
+}
+impl Probe {
+    /// Checks whether `T` expects an object with `tag` without reading an object
+    fn of<'a, T: Deserialize<'a>>(tag: u8, options: Options) -> Self {
+        let result = Cell::new(Probe { optional: false, claims: true });
+        let _ = T::deserialize(TagProbe { tag, implicit_tag: None, optional: false, options, result: &result });
+        result.get()
+    }
+}
+
+/// A deserializer that only checks the tag a type expects
+///
+/// _Note: The tag check is recorded in `result` and then deserialization is aborted with an error, so that the value is
+/// never built_
+struct TagProbe<'p> {
+    tag: u8,
+    /// A tag that replaces the universal tag of the object (IMPLICIT tagging)
+    implicit_tag: Option<u8>,
+    /// Whether the type is wrapped into an `Option`
+    optional: bool,
+    options: Options,
+    result: &'p Cell<Probe>,
+}
+impl<'p> TagProbe<'p> {
+    /// Whether the tag is the implicit tag if set or the universal `tag` otherwise
+    ///
+    /// _Note: Like `Deserializer::check_tag`, the constructed form of a primitive tag is claimed so that it is rejected_
+    fn claims(&self, tag: u8) -> bool {
+        let expected = expected_tag(self.implicit_tag, tag);
+        self.tag == expected || (expected & 0x20 == 0 && expected | 0x20 == self.tag)
+    }
+    /// Whether the tag is a string tag that `Deserializer::load_str` accepts
+    fn claims_str(&self) -> bool {
+        let any = [NUMERIC_STRING_TAG, PRINTABLE_STRING_TAG, IA5_STRING_TAG, VISIBLE_STRING_TAG];
+        let any = self.implicit_tag.is_none() && self.options.accept_any_string_tag && any.contains(&self.tag);
+        any || self.claims(Utf8String::TAG)
+    }
+    /// Records the tag check and aborts the deserialization
+    fn record<T>(self, claims: bool) -> Result<T> {
+        self.result.set(Probe { optional: self.optional, claims });
+        Err(eother!("The tag has been probed"))?
+    }
+}
+/// Implements the methods of `TagProbe` for types with a fixed universal tag
+macro_rules! probe_tag {
+    ($($method:ident($($type:ty),*) => $tag:expr;)*) => {$(
+        fn $method<V: Visitor<'de>>(self, $(_: $type,)* _visitor: V) -> Result<V::Value> {
+            let claims = self.claims($tag);
+            self.record(claims)
+        }
+    )*};
+}
+impl<'de, 'p> serde::Deserializer<'de> for TagProbe<'p> {
+    type Error = SerdeAsn1DerError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        self.record(true)
+    }
+    fn deserialize_bool<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        let integer = self.options.bool_from_integer && self.implicit_tag.is_none() && self.tag == Integer::TAG;
+        let claims = integer || self.claims(Boolean::TAG);
+        self.record(claims)
+    }
+    probe_tag! {
+        deserialize_i8() => Integer::TAG;
+        deserialize_i16() => Integer::TAG;
+        deserialize_i32() => Integer::TAG;
+        deserialize_i64() => Integer::TAG;
+        deserialize_i128() => Integer::TAG;
+        deserialize_u8() => Integer::TAG;
+        deserialize_u16() => Integer::TAG;
+        deserialize_u32() => Integer::TAG;
+        deserialize_u64() => Integer::TAG;
+        deserialize_u128() => Integer::TAG;
+        deserialize_f32() => real::TAG;
+        deserialize_f64() => real::TAG;
+        deserialize_bytes() => OctetString::TAG;
+        deserialize_byte_buf() => OctetString::TAG;
+        deserialize_unit() => Null::TAG;
+        deserialize_unit_struct(&'static str) => Null::TAG;
+        deserialize_seq() => Sequence::TAG;
+        deserialize_tuple(usize) => Sequence::TAG;
+        deserialize_tuple_struct(&'static str, usize) => Sequence::TAG;
+        deserialize_map() => Sequence::TAG;
+        deserialize_struct(&'static str, &'static [&'static str]) => Sequence::TAG;
+    }
+    fn deserialize_char<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        let claims = self.claims_str();
+        self.record(claims)
+    }
+    fn deserialize_str<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        let claims = self.claims_str();
+        self.record(claims)
+    }
+    fn deserialize_string<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        let claims = self.claims_str();
+        self.record(claims)
+    }
 
-        // Deserialize the next object
-        let mut deserializer = Deserializer { object };
-        let next = seed.deserialize(&mut deserializer)?;
-        Ok(Some(next))
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.tag {
+            Null::TAG => self.record(true),
+            _ => visitor.visit_some(TagProbe { optional: true, ..self }),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        #[cfg(feature = "more_types")]
+        match Special::for_name(_name) {
+            Some(Special::Primitive(tag)) => {
+                let claims = self.tag == self.implicit_tag.unwrap_or(tag);
+                return self.record(claims);
+            }
+            Some(Special::RestrictedString(tag) | Special::Explicit(tag)) => {
+                let claims = self.claims(tag);
+                return self.record(claims);
+            }
+            Some(Special::Set | Special::SetOf | Special::SetOfRaw) => {
+                let claims = self.claims(types::SET_TAG);
+                return self.record(claims);
+            }
+            Some(Special::Implicit(number)) => {
+                return match self.tag & !0x20 == 0x80 | number {
+                    true => visitor.visit_newtype_struct(TagProbe { implicit_tag: Some(0x80 | number), ..self }),
+                    false => self.record(false),
+                };
+            }
+            Some(Special::Spanned) => return visitor.visit_seq(SpannedAccess { deserializer: Some(self), raw: None }),
+            Some(Special::Absent) | None => (),
+        }
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value> {
+        // CHOICEs claim any context-specific constructed tag
+        let claims = self.tag & 0xe0 == 0xa0;
+        #[cfg(feature = "more_types")]
+        let claims = claims || self.tag & !0x20 == self.implicit_tag.unwrap_or(types::ENUMERATED_TAG);
+        self.record(claims)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        self.record(true)
+    }
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value> {
+        self.record(true)
     }
 }
 
-/// An ASN.1-DER deserializer over a `slice`
-struct Deserializer<'a> {
+/// An enum access for CHOICEs that maps the context-specific tag number to the index of a newtype variant
+struct ChoiceAccess<'a> {
+    index: u32,
     object: DerObject<'a>,
+    /// The nesting depth of the wrapped object
+    depth: usize,
+    options: Options,
 }
-impl<'a, 'r> serde::de::Deserializer<'a> for &'r mut Deserializer<'a> {
+impl<'a> EnumAccess<'a> for ChoiceAccess<'a> {
+    type Error = SerdeAsn1DerError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'a>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let deserializer: U32Deserializer<SerdeAsn1DerError> = self.index.into_deserializer();
+        let variant = seed.deserialize(deserializer)?;
+        Ok((variant, self))
+    }
+}
+impl<'a> VariantAccess<'a> for ChoiceAccess<'a> {
+    type Error = SerdeAsn1DerError;
+
+    fn unit_variant(self) -> Result<()> {
+        Err(eunsupported!("Unit variants cannot be read from a CHOICE"))?
+    }
+    fn newtype_variant_seed<T: DeserializeSeed<'a>>(self, seed: T) -> Result<T::Value> {
+        seed.deserialize(&mut Deserializer::new(self.object, self.depth, self.options))
+    }
+    fn tuple_variant<V: Visitor<'a>>(self, _len: usize, _visitor: V) -> Result<V::Value> {
+        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
+    }
+    fn struct_variant<V: Visitor<'a>>(self, _fields: &'static [&'static str], _visitor: V) -> Result<V::Value> {
+        Err(eunsupported!("Struct variants are not supported by this implementation"))?
+    }
+}
+
+/// A sequence access for `Spanned` that yields the value and then the raw object it is read from
+#[cfg(feature = "more_types")]
+struct SpannedAccess<'a, D> {
+    /// The deserializer for the value or `None` if the value has been read
+    deserializer: Option<D>,
+    /// The raw object or `None` if it has been read
+    raw: Option<&'a [u8]>,
+}
+#[cfg(feature = "more_types")]
+impl<'a, D: serde::Deserializer<'a, Error = SerdeAsn1DerError>> SeqAccess<'a> for SpannedAccess<'a, D> {
+    type Error = SerdeAsn1DerError;
+
+    fn next_element_seed<T: DeserializeSeed<'a>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if let Some(deserializer) = self.deserializer.take() {
+            return seed.deserialize(deserializer).map(Some);
+        }
+        match self.raw.take() {
+            Some(raw) => seed.deserialize(BorrowedBytesDeserializer::new(raw)).map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// An enum access for ENUMERATEDs that maps the value to the index of a unit variant
+#[cfg(feature = "more_types")]
+struct EnumeratedAccess(u32);
+#[cfg(feature = "more_types")]
+impl<'a> EnumAccess<'a> for EnumeratedAccess {
+    type Error = SerdeAsn1DerError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'a>>(self, seed: V) -> Result<(V::Value, Self::Variant)> {
+        let deserializer: U32Deserializer<SerdeAsn1DerError> = self.0.into_deserializer();
+        let variant = seed.deserialize(deserializer)?;
+        Ok((variant, self))
+    }
+}
+#[cfg(feature = "more_types")]
+impl<'a> VariantAccess<'a> for EnumeratedAccess {
+    type Error = SerdeAsn1DerError;
+
+    fn unit_variant(self) -> Result<()> {
+        Ok(())
+    }
+    fn newtype_variant_seed<T: DeserializeSeed<'a>>(self, _seed: T) -> Result<T::Value> {
+        Err(eunsupported!("Newtype variants are not supported by this implementation"))?
+    }
+    fn tuple_variant<V: Visitor<'a>>(self, _len: usize, _visitor: V) -> Result<V::Value> {
+        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
+    }
+    fn struct_variant<V: Visitor<'a>>(self, _fields: &'static [&'static str], _visitor: V) -> Result<V::Value> {
+        Err(eunsupported!("Struct variants are not supported by this implementation"))?
+    }
+}
+
+/// An ASN.1-DER deserializer
+pub struct Deserializer<'a> {
+    object: DerObject<'a>,
+    /// A tag that replaces the universal tag of the object (IMPLICIT tagging)
+    implicit_tag: Option<u8>,
+    /// The amount of bytes that follow the top-level object in the input
+    trailing_len: usize,
+    /// The amount of constructed objects that enclose the object
+    depth: usize,
+    /// Whether the object is a SET whose elements are assigned to the fields of the target by their tags
+    #[cfg(feature = "more_types")]
+    set: bool,
+    options: Options,
+}
+impl<'a> Deserializer<'a> {
+    /// Creates a new deserializer for the first top-level object in `bytes`
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self> {
+        let object = misc::decode_object_at(bytes, 0).propagate(e!("Failed to decode DER object"))?;
+        reject_eoc(&object)?;
+        Ok(Self { trailing_len: bytes.len() - object.raw().len(), ..Self::new(object, 0, Options::default()) })
+    }
+    /// Copies the first top-level object from `source` into `backing` and creates a new deserializer for it
+    ///
+    /// _Note: To limit the memory consumption, objects with a declared length above 64 MiB are rejected with
+    /// `LengthLimitExceeded`_
+    pub fn from_source(source: impl Source, backing: impl Sink + Into<&'a [u8]>) -> Result<Self> {
+        Self::from_source_with_max_length(source, backing, MAX_LENGTH)
+    }
+    /// Copies the first top-level object from `source` into `backing` and creates a new deserializer for it, where
+    /// objects with a declared length above `max_length` are rejected with `LengthLimitExceeded`
+    pub fn from_source_with_max_length(
+        source: impl Source,
+        backing: impl Sink + Into<&'a [u8]>,
+        max_length: usize,
+    ) -> Result<Self> {
+        let object = read_object(source, backing, max_length)?;
+        reject_eoc(&object)?;
+        Ok(Self::new(object, 0, Options::default()))
+    }
+    /// Converts the first top-level BER object in `bytes` into DER, copies it into `backing` and creates a new
+    /// deserializer for it
+    ///
+    /// _Note: Indefinite lengths and non-minimal long-form lengths (e.g. `0x82 0x00 0x05`) are replaced by minimal
+    /// definite ones, which are only accepted by this constructor. Use `set_lenient_bool` to accept BER booleans too._
+    pub fn from_ber(bytes: &[u8], backing: impl Sink + Into<&'a [u8]>) -> Result<Self> {
+        let der = ber::to_der(bytes)?;
+        Self::from_source(der.iter(), backing)
+    }
+    /// Creates a new deserializer for `object`, which is enclosed by `depth` constructed objects
+    fn new(object: DerObject<'a>, depth: usize, options: Options) -> Self {
+        Self {
+            object,
+            implicit_tag: None,
+            trailing_len: 0,
+            depth,
+            #[cfg(feature = "more_types")]
+            set: false,
+            options,
+        }
+    }
+
+    /// The amount of bytes that follow the top-level object in the input
+    ///
+    /// _Note: This is always `0` for deserializers that read from a source, since only the object itself is read_
+    pub fn trailing_len(&self) -> usize {
+        self.trailing_len
+    }
+
+    /// The content octets (without tag and length) of the object, borrowed from the input
+    pub fn content_borrowed(&self) -> &'a [u8] {
+        self.object.value()
+    }
+
+    /// Sets whether sequence elements that are not consumed by the target type are skipped (`true`; default) or
+    /// rejected (`false`)
+    ///
+    /// This allows forward-compatible parsing of sequences that have been extended with new trailing elements.
+    pub fn set_allow_trailing_seq_elements(&mut self, allow: bool) {
+        self.options.allow_trailing_seq_elements = allow;
+    }
+
+    /// Sets whether strings can be read from NumericString-, PrintableString-, IA5String- and VisibleString-objects too
+    /// (`true`) or from UTF8String-objects only (`false`; default)
+    ///
+    /// This is useful for e.g. distinguished names, where the string type of the components varies.
+    pub fn set_accept_any_string_tag(&mut self, accept: bool) {
+        self.options.accept_any_string_tag = accept;
+    }
+
+    /// Sets whether errors within sequences are wrapped into `SerdeAsn1DerError::AtPath` with the path to the failing
+    /// element (`true`) or returned as they are (`false`; default)
+    ///
+    /// The path consists of the field names of structs and the indices of other sequence elements (e.g.
+    /// `tbs_certificate.validity.not_after` or `extensions.2`).
+    pub fn set_track_path(&mut self, track: bool) {
+        self.options.track_path = track;
+    }
+
+    /// Sets whether booleans can be read from INTEGER-objects with the value `0` or `1` too (`true`) or from
+    /// BOOLEAN-objects only (`false`; default)
+    ///
+    /// This is useful to interoperate with non-conformant encoders that encode booleans as integers.
+    pub fn set_bool_from_integer(&mut self, accept: bool) {
+        self.options.bool_from_integer = accept;
+    }
+    /// Sets whether any non-zero BOOLEAN value is read as `true` as in BER (`true`) or only `0xff` as required by DER
+    /// (`false`; default)
+    pub fn set_lenient_bool(&mut self, lenient: bool) {
+        self.options.lenient_bool = lenient;
+    }
+
+    /// Ensures that the object has the implicit tag if set or the universal `tag` otherwise
+    ///
+    /// _Note: The implicit tag takes the constructed bit of `tag`. If a primitive tag is expected, the constructed form
+    /// of that tag is rejected with `ConstructedNotAllowed`, any other mismatch with `UnexpectedTag`_
+    fn check_tag(&self, tag: u8) -> Result<()> {
+        let expected = expected_tag(self.implicit_tag, tag);
+        match self.object.tag() {
+            found if found == expected => Ok(()),
+            found if expected & 0x20 == 0 && expected | 0x20 == found => {
+                Err(SerdeAsn1DerError::ConstructedNotAllowed(found))
+            }
+            found => Err(SerdeAsn1DerError::UnexpectedTag { expected, found }),
+        }
+    }
+
+    /// Loads the two's complement value of the object as integer
+    fn load_integer(&self) -> Result<&'a [u8]> {
+        self.check_tag(Integer::TAG)?;
+
+        // Guard against a mismatch between the declared length and the actual content length
+        let value = self.object.value();
+        let declared = der::length::decode(&mut self.object.header()[1..].iter());
+        if !matches!(declared, Ok(Some(len)) if len == value.len()) {
+            Err(einval!("The integer length does not match the object length"))?;
+        }
+
+        // Validate the encoding
+        match value {
+            [] => Err(einval!("DER object is not a valid integer"))?,
+            [0x00, next, ..] if next & 0x80 == 0 => Err(einval!("DER object is not a valid integer"))?,
+            [0xff, next, ..] if next & 0x80 != 0 => Err(einval!("DER object is not a valid integer"))?,
+            _ => Ok(value),
+        }
+    }
+    /// Loads the object as unsigned integer
+    fn load_unsigned(&self) -> Result<u128> {
+        // Strip the sign byte and decode the number
+        let bytes = match self.load_integer()? {
+            [first, ..] if first & 0x80 != 0 => Err(eunsupported!("The numeric value is negative"))?,
+            [0x00, rest @ ..] => rest,
+            value => value,
+        };
+        if bytes.len() > 16 {
+            Err(eunsupported!("The numeric value is too large for `u128` (use `BigInteger` instead)"))?;
+        }
+        Ok(bytes.iter().fold(0, |num, byte| (num << 8) | *byte as u128))
+    }
+    /// Loads the object as signed integer
+    fn load_signed(&self) -> Result<i128> {
+        let bytes = self.load_integer()?;
+        if bytes.len() > 16 {
+            Err(eunsupported!("The numeric value is too large for `i128` (use `BigInteger` instead)"))?;
+        }
+
+        // Sign-extend the number
+        let init = match bytes[0] & 0x80 {
+            0 => 0,
+            _ => -1,
+        };
+        Ok(bytes.iter().fold(init, |num, byte| (num << 8) | *byte as i128))
+    }
+
+    /// Loads the object as string
+    ///
+    /// _Note: NumericString-, PrintableString-, IA5String- and VisibleString-objects are only accepted if
+    /// `accept_any_string_tag` is set_
+    fn load_str(&self) -> Result<&'a str> {
+        // Read implicitly tagged strings as UTF8String
+        let tag = match self.implicit_tag {
+            Some(_) => {
+                self.check_tag(Utf8String::TAG)?;
+                Utf8String::TAG
+            }
+            None => self.object.tag(),
+        };
+        self.load_str_as(tag)
+    }
+    /// Loads the value of the object as string with the given string `tag`
+    fn load_str_as(&self, tag: u8) -> Result<&'a str> {
+        // Validate the character set
+        let value = self.object.value();
+        let valid = match tag {
+            Utf8String::TAG => true,
+            tag @ (NUMERIC_STRING_TAG | PRINTABLE_STRING_TAG | IA5_STRING_TAG | VISIBLE_STRING_TAG)
+                if self.options.accept_any_string_tag =>
+            {
+                misc::is_valid_string(tag, value)
+            }
+            tag if tag & 0x20 != 0
+                && [Utf8String::TAG, NUMERIC_STRING_TAG, PRINTABLE_STRING_TAG, IA5_STRING_TAG, VISIBLE_STRING_TAG]
+                    .contains(&(tag ^ 0x20)) =>
+            {
+                return Err(SerdeAsn1DerError::ConstructedNotAllowed(tag));
+            }
+            found => return Err(SerdeAsn1DerError::UnexpectedTag { expected: Utf8String::TAG, found }),
+        };
+        if !valid {
+            Err(einval!("String object contains invalid characters"))?;
+        }
+
+        // #implicit_validation: The other string types are subsets of UTF-8
+        let s = std::str::from_utf8(value).map_err(|_| einval!("String object is not valid UTF-8"))?;
+        Ok(s)
+    }
+
+    /// The nesting depth of the objects within the object
+    ///
+    /// _Note: Like the serializer, this limits the nesting depth to avoid a stack overflow on deeply nested input_
+    fn inner_depth(&self) -> Result<usize> {
+        match self.depth < DEPTH_LIMIT {
+            true => Ok(self.depth + 1),
+            false => Err(SerdeAsn1DerError::DepthLimitExceeded),
+        }
+    }
+
+    /// Loads the single object that is wrapped by the explicitly tagged object
+    fn load_explicit(&self) -> Result<DerObject<'a>> {
+        let object = match misc::decode_object_at(self.object.value(), 0) {
+            // #implicit_validation: Decoding from a slice can only fail with an I/O error if the slice is truncated
+            Err(Asn1DerError { error: Asn1DerErrorVariant::InOutError(_), .. }) => {
+                Err(einval!("Explicitly tagged object is shorter than the wrapped object"))?
+            }
+            object => object.propagate(e!("Invalid explicitly tagged object"))?,
+        };
+        if object.raw().len() != self.object.value().len() {
+            Err(einval!("Explicitly tagged object contains more than one object"))?;
+        }
+        Ok(object)
+    }
+
+    /// Passes the elements of the sequence to `visitor`, where `fields` is the amount of fields of the target struct
+    /// or tuple if any and `names` are the field names of the target struct if any
+    fn deserialize_sequence<V: Visitor<'a>>(
+        &mut self,
+        fields: Option<usize>,
+        names: Option<&'static [&'static str]>,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.check_tag(Sequence::TAG)?;
+        #[cfg(feature = "more_types")]
+        if let (true, Some(fields)) = (self.set, fields) {
+            let mut reader = SetReader::new(self.object.value(), fields, names, self.inner_depth()?, self.options)?;
+            let value = visitor.visit_seq(&mut reader)?;
+            reader.finish()?;
+            return Ok(value);
+        }
+
+        let mut reader = SequenceReader::new(self.object.value(), fields, names, self.inner_depth()?, self.options)?;
+        let value = visitor.visit_seq(&mut reader)?;
+        reader.finish()?;
+        Ok(value)
+    }
+}
+impl<'a> serde::de::Deserializer<'a> for &mut Deserializer<'a> {
     type Error = SerdeAsn1DerError;
 
     #[inline]
@@ -58,85 +936,124 @@ impl<'a, 'r> serde::de::Deserializer<'a> for &'r mut Deserializer<'a> {
                 }
             }
             Null::TAG => self.deserialize_option(visitor),
+            real::TAG => self.deserialize_f64(visitor),
             OctetString::TAG => self.deserialize_byte_buf(visitor),
             Sequence::TAG => self.deserialize_seq(visitor),
             Utf8String::TAG => self.deserialize_string(visitor),
+            // Pass times as ISO 8601 string so that both time types have the same representation
+            tag @ (UTC_TIME_TAG | GENERALIZED_TIME_TAG) => visitor.visit_string(time_to_iso(tag, self.object.value())?),
+            // Read primitive context-specific objects as implicitly tagged bytes
+            tag if tag & 0xe0 == 0x80 => {
+                let mut deserializer = Deserializer { implicit_tag: Some(tag), ..*self };
+                deserializer.deserialize_bytes(visitor)
+            }
+            // Read constructed context-specific objects as explicitly tagged newtype
+            tag if tag & 0xe0 == 0xa0 => {
+                let object = self.load_explicit()?;
+                visitor.visit_newtype_struct(&mut Deserializer::new(object, self.inner_depth()?, self.options))
+            }
             _ => Err(eunsupported!("The object type is not supported by this implementation"))?,
         }
     }
 
     fn deserialize_bool<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let bool = bool::load(self.object).propagate(e!("Failed to load object"))?;
+        // Read INTEGERs with the value `0` or `1` if enabled
+        if self.options.bool_from_integer && self.implicit_tag.is_none() && self.object.tag() == Integer::TAG {
+            let bool = match self.object.value() {
+                [0x00] => false,
+                [0x01] => true,
+                _ => Err(einval!("INTEGER object is not a valid boolean"))?,
+            };
+            return visitor.visit_bool(bool);
+        }
+
+        self.check_tag(Boolean::TAG)?;
+        let bool = match self.object.value() {
+            [0x00] => false,
+            [0xff] => true,
+            [_] if self.options.lenient_bool => true,
+            _ => Err(einval!("DER object is not a valid boolean"))?,
+        };
         visitor.visit_bool(bool)
     }
 
-    fn deserialize_i8<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_i8<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let i8 = i8::try_from(self.load_signed()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
+        visitor.visit_i8(i8)
     }
-    fn deserialize_i16<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_i16<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let i16 = i16::try_from(self.load_signed()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
+        visitor.visit_i16(i16)
     }
-    fn deserialize_i32<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_i32<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let i32 = i32::try_from(self.load_signed()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
+        visitor.visit_i32(i32)
     }
-    fn deserialize_i64<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_i64<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let i64 = i64::try_from(self.load_signed()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
+        visitor.visit_i64(i64)
     }
     //noinspection RsTraitImplementation
-    fn deserialize_i128<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_i128<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        let i128 = self.load_signed()?;
+        visitor.visit_i128(i128)
     }
 
     fn deserialize_u8<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let u8 = u8::load(self.object).propagate(e!("Failed to load object"))?;
+        let u8 = u8::try_from(self.load_unsigned()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
         visitor.visit_u8(u8)
     }
     fn deserialize_u16<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let u16 = u16::load(self.object).propagate(e!("Failed to load object"))?;
+        let u16 = u16::try_from(self.load_unsigned()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
         visitor.visit_u16(u16)
     }
     fn deserialize_u32<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let u32 = u32::load(self.object).propagate(e!("Failed to load object"))?;
+        let u32 = u32::try_from(self.load_unsigned()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
         visitor.visit_u32(u32)
     }
     fn deserialize_u64<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let u64 = u64::load(self.object).propagate(e!("Failed to load object"))?;
+        let u64 = u64::try_from(self.load_unsigned()?).map_err(|_| eunsupported!("The numeric value is too large"))?;
         visitor.visit_u64(u64)
     }
     //noinspection RsTraitImplementation
     fn deserialize_u128<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let u128 = u128::load(self.object).propagate(e!("Failed to load object"))?;
+        let u128 = self.load_unsigned()?;
         visitor.visit_u128(u128)
     }
 
-    fn deserialize_f32<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_f32<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        self.check_tag(real::TAG)?;
+        let f64 = real::decode(self.object.value())?;
+        match f64 as f32 {
+            f32 if f64::from(f32) == f64 || f64.is_nan() => visitor.visit_f32(f32),
+            _ => Err(eunsupported!("The real value cannot be represented as `f32`"))?,
+        }
     }
-    fn deserialize_f64<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn deserialize_f64<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        self.check_tag(real::TAG)?;
+        let f64 = real::decode(self.object.value())?;
+        visitor.visit_f64(f64)
     }
 
     fn deserialize_char<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let s = Utf8String::load(self.object).propagate(e!("Failed to load object"))?;
-        let c = s.get().chars().next().ok_or(einval!("Cannot read char from empty string object"))?;
+        let c = self.load_str()?.chars().next().ok_or(einval!("Cannot read char from empty string object"))?;
         visitor.visit_char(c)
     }
     fn deserialize_str<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let s = Utf8String::load(self.object).propagate(e!("Failed to load object"))?;
-        visitor.visit_str(s.get())
+        visitor.visit_borrowed_str(self.load_str()?)
     }
     fn deserialize_string<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let string = String::load(self.object).propagate(e!("Failed to load object"))?;
-        visitor.visit_string(string)
+        visitor.visit_string(self.load_str()?.to_string())
     }
 
     fn deserialize_bytes<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let bytes = OctetString::load(self.object).propagate(e!("Failed to load object"))?;
-        visitor.visit_bytes(bytes.get())
+        // Pass the bytes borrowed from the input to allow zero-copy deserialization
+        self.check_tag(OctetString::TAG)?;
+        visitor.visit_borrowed_bytes(self.object.value())
     }
     fn deserialize_byte_buf<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let bytes = Vec::<u8>::load(self.object).propagate(e!("Failed to load object"))?;
-        visitor.visit_byte_buf(bytes)
+        self.check_tag(OctetString::TAG)?;
+        visitor.visit_byte_buf(self.object.value().to_vec())
     }
 
     fn deserialize_option<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
@@ -147,7 +1064,10 @@ impl<'a, 'r> serde::de::Deserializer<'a> for &'r mut Deserializer<'a> {
     }
 
     fn deserialize_unit<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        Null::load(self.object).propagate(e!("Failed to load object"))?;
+        self.check_tag(Null::TAG)?;
+        if !self.object.value().is_empty() {
+            Err(einval!("DER object is not a valid null object"))?;
+        }
         visitor.visit_unit()
     }
     //noinspection RsUnresolvedReference
@@ -160,48 +1080,128 @@ impl<'a, 'r> serde::de::Deserializer<'a> for &'r mut Deserializer<'a> {
     // insignificant wrappers around the data they contain. That means not
     // parsing anything other than the contained value.
     fn deserialize_newtype_struct<V: Visitor<'a>>(self, _name: &'static str, visitor: V) -> Result<V::Value> {
+        #[cfg(feature = "more_types")]
+        match Special::for_name(_name) {
+            // Pass the raw payload to the type-specific visitor
+            Some(Special::Primitive(tag)) => {
+                return match (self.object.tag(), self.implicit_tag.unwrap_or(tag)) {
+                    (found, expected) if found == expected => visitor.visit_borrowed_bytes(self.object.value()),
+                    (found, expected) => Err(SerdeAsn1DerError::UnexpectedTag { expected, found }),
+                };
+            }
+            // Read the string after validating the character set
+            Some(Special::RestrictedString(tag)) => {
+                self.check_tag(tag)?;
+                let options = Options { accept_any_string_tag: true, ..self.options };
+                let deserializer = Deserializer { options, ..*self };
+                return visitor.visit_borrowed_str(deserializer.load_str_as(tag)?);
+            }
+            // Validate the element order and read the set as implicitly tagged sequence
+            Some(Special::SetOf) => {
+                self.check_tag(types::SET_TAG)?;
+                types::validate_set_order(self.object.value())?;
+                let implicit_tag = Some(self.implicit_tag.unwrap_or(types::SET_TAG));
+                let mut deserializer = Deserializer { implicit_tag, ..*self };
+                return visitor.visit_newtype_struct(&mut deserializer);
+            }
+            // Validate the element order and assign the elements to the fields by their tags
+            Some(Special::Set) => {
+                self.check_tag(types::SET_TAG)?;
+                types::validate_set_order(self.object.value())?;
+                let implicit_tag = Some(self.implicit_tag.unwrap_or(types::SET_TAG));
+                let mut deserializer = Deserializer { implicit_tag, set: true, ..*self };
+                return visitor.visit_newtype_struct(&mut deserializer);
+            }
+            // Read the set as implicitly tagged sequence in wire order
+            Some(Special::SetOfRaw) => {
+                let implicit_tag = Some(self.implicit_tag.unwrap_or(types::SET_TAG));
+                let mut deserializer = Deserializer { implicit_tag, ..*self };
+                return visitor.visit_newtype_struct(&mut deserializer);
+            }
+            // Read the value with the context-specific tag instead of its universal tag
+            Some(Special::Implicit(number)) => {
+                if self.object.tag() & !0x20 != 0x80 | number {
+                    // The constructed bit is checked by the type
+                    let found = self.object.tag();
+                    return Err(SerdeAsn1DerError::UnexpectedTag { expected: 0x80 | number | (found & 0x20), found });
+                }
+                let mut deserializer = Deserializer { implicit_tag: Some(0x80 | number), ..*self };
+                return visitor.visit_newtype_struct(&mut deserializer);
+            }
+            // Read the single object that is wrapped by the tagged object
+            Some(Special::Explicit(tag)) => {
+                self.check_tag(tag)?;
+                let object = self.load_explicit()?;
+                return visitor.visit_newtype_struct(&mut Deserializer::new(object, self.inner_depth()?, self.options));
+            }
+            // Read the value from this object and pass the raw object alongside
+            Some(Special::Spanned) => {
+                let raw = Some(self.object.raw());
+                return visitor.visit_seq(SpannedAccess { deserializer: Some(self), raw });
+            }
+            Some(Special::Absent) | None => (),
+        }
         visitor.visit_newtype_struct(self)
     }
 
     fn deserialize_seq<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
-        let sequence = Sequence::load(self.object).propagate(e!("Failed to load object"))?;
-        visitor.visit_seq(SequenceReader { sequence, pos: 0 })
+        self.deserialize_sequence(None, None, visitor)
     }
     //noinspection RsUnresolvedReference
-    fn deserialize_tuple<V: Visitor<'a>>(self, _len: usize, visitor: V) -> Result<V::Value> {
-        self.deserialize_seq(visitor)
+    fn deserialize_tuple<V: Visitor<'a>>(self, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_sequence(Some(len), None, visitor)
     }
     //noinspection RsUnresolvedReference
-    fn deserialize_tuple_struct<V: Visitor<'a>>(
-        self,
-        _name: &'static str,
-        _len: usize,
-        visitor: V,
-    ) -> Result<V::Value> {
-        self.deserialize_seq(visitor)
+    fn deserialize_tuple_struct<V: Visitor<'a>>(self, _name: &'static str, len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_sequence(Some(len), None, visitor)
     }
 
+    #[cfg(not(feature = "map"))]
     fn deserialize_map<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+        Err(eunsupported!("Deserializing maps requires the `map`-feature"))?
+    }
+    #[cfg(feature = "map")]
+    fn deserialize_map<V: Visitor<'a>>(self, visitor: V) -> Result<V::Value> {
+        self.check_tag(Sequence::TAG)?;
+        let depth = self.inner_depth()?;
+        let reader = MapReader { entries: self.object.value(), pos: 0, value: None, depth, options: self.options };
+        visitor.visit_map(reader)
     }
 
     //noinspection RsUnresolvedReference
     fn deserialize_struct<V: Visitor<'a>>(
         self,
         _name: &'static str,
-        _fields: &'static [&'static str],
+        fields: &'static [&'static str],
         visitor: V,
     ) -> Result<V::Value> {
-        self.deserialize_seq(visitor)
+        self.deserialize_sequence(Some(fields.len()), Some(fields), visitor)
     }
 
     fn deserialize_enum<V: Visitor<'a>>(
         self,
         _name: &'static str,
         _variants: &'static [&'static str],
-        _visitor: V,
+        visitor: V,
     ) -> Result<V::Value> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+        match self.object.tag() {
+            // Read the variant index of a CHOICE from the context-specific tag
+            tag if tag & 0xe0 == 0xa0 => {
+                let (object, depth) = (self.load_explicit()?, self.inner_depth()?);
+                visitor.visit_enum(ChoiceAccess { index: u32::from(tag & 0x1f), object, depth, options: self.options })
+            }
+            // Read the variant index from the ENUMERATED
+            #[cfg(feature = "more_types")]
+            _ => {
+                let implicit_tag = Some(self.implicit_tag.unwrap_or(types::ENUMERATED_TAG));
+                let deserializer = Deserializer { implicit_tag, ..*self };
+                let index = u32::try_from(deserializer.load_unsigned()?)
+                    .map_err(|_| eunsupported!("The enumerated value is too large"))?;
+                visitor.visit_enum(EnumeratedAccess(index))
+            }
+            #[cfg(not(feature = "more_types"))]
+            _ => Err(eunsupported!("The object type is not supported by this implementation"))?,
+        }
     }
 
     fn deserialize_identifier<V: Visitor<'a>>(self, _visitor: V) -> Result<V::Value> {
@@ -224,17 +1224,88 @@ impl<'a, 'r> serde::de::Deserializer<'a> for &'r mut Deserializer<'a> {
     }
 }
 
+/// An iterator over the elements of a SEQUENCE OF that reads one element at a time from the underlying reader
+pub struct SequenceIter<R: Read, T> {
+    source: ReaderSource<R>,
+    /// The amount of bytes of the sequence that have not been read yet
+    remaining: usize,
+    /// The buffer for the current element
+    buf: Vec<u8>,
+    _element: PhantomData<T>,
+}
+impl<R: Read, T: DeserializeOwned> SequenceIter<R, T> {
+    /// Reads and deserializes the next element
+    fn read_next(&mut self) -> Result<T> {
+        self.buf.clear();
+        let object = read_object(&mut self.source, VecBacking(&mut self.buf), MAX_LENGTH)?;
+        self.remaining = (self.remaining.checked_sub(object.raw().len()))
+            .ok_or(einval!("The sequence element exceeds the sequence"))?;
+        reject_eoc(&object)?;
+        T::deserialize(&mut Deserializer::new(object, 0, Options::default()))
+    }
+}
+impl<R: Read, T: DeserializeOwned> Iterator for SequenceIter<R, T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        // Stop after the first error since the position within the sequence is undefined
+        let next = self.read_next();
+        if next.is_err() {
+            self.remaining = 0;
+        }
+        Some(next)
+    }
+}
+
 /// Deserializes `T` from `bytes`
+///
+/// _Note: `bytes` must contain exactly one object; trailing data is rejected with `TrailingData`. To read the first
+/// object only, use `Deserializer::from_bytes`._
 pub fn from_bytes<'a, T: Deserialize<'a>>(bytes: &'a [u8]) -> Result<T> {
-    let object = DerObject::decode(bytes).propagate(e!("Failed to decode DER object"))?;
-    T::deserialize(&mut Deserializer { object })
+    let mut deserializer = Deserializer::from_bytes(bytes)?;
+    if deserializer.trailing_len() > 0 {
+        return Err(SerdeAsn1DerError::TrailingData);
+    }
+    T::deserialize(&mut deserializer)
+}
+/// Converts the first top-level BER object in `bytes` into DER, copies it into `backing` and deserializes it from
+/// there
+///
+/// _Note: This accepts indefinite lengths and non-minimal long-form lengths, which are rejected by the other functions_
+pub fn from_ber<'a, T: Deserialize<'a>>(bytes: &[u8], backing: impl Sink + Into<&'a [u8]>) -> Result<T> {
+    T::deserialize(&mut Deserializer::from_ber(bytes, backing)?)
 }
 /// Copies the first top-level object from `reader` into `backing` and deserializes it from there
 pub fn from_reader<'a, T: Deserialize<'a>>(reader: impl Read, backing: impl Sink + Into<&'a [u8]>) -> Result<T> {
     from_source(ReaderSource(reader), backing)
 }
+/// Copies the first top-level object from `reader` into `backing` and deserializes it from there
+///
+/// _Note: Unlike `from_reader`, this reads directly from the buffer of `reader`; only the bytes of the object are
+/// consumed._
+pub fn from_bufread<'a, T: Deserialize<'a>>(reader: impl BufRead, backing: impl Sink + Into<&'a [u8]>) -> Result<T> {
+    from_source(BufReadSource(reader), backing)
+}
+/// Reads the header of a SEQUENCE OF from `reader` and returns an iterator that reads and deserializes the elements
+/// one by one
+///
+/// _Note: Unlike `from_reader`, this only buffers one element at a time, so that huge sequences (like CRLs) can be
+/// processed. `reader` is read byte by byte, so consider wrapping it into a `BufReader`._
+pub fn from_reader_iter<T: DeserializeOwned, R: Read>(reader: R) -> Result<SequenceIter<R, T>> {
+    let mut source = ReaderSource(reader);
+    if source.read().propagate(e!("Failed to read tag"))? != Sequence::TAG {
+        Err(einval!("DER object is not a valid sequence"))?;
+    }
+    let remaining = der::length::decode(&mut source)
+        .propagate(e!("Failed to decode length"))?
+        .ok_or(einval!("The sequence length is truncated"))?;
+    Ok(SequenceIter { source, remaining, buf: Vec::new(), _element: PhantomData })
+}
 /// Copies the first top-level object from `source` into `backing` and deserializes it from there
-pub fn from_source<'a, T: Deserialize<'a>>(mut source: impl Source, backing: impl Sink + Into<&'a [u8]>) -> Result<T> {
-    let object = DerObject::decode_from_source(&mut source, backing).propagate(e!("Failed to decode DER object"))?;
-    T::deserialize(&mut Deserializer { object })
+pub fn from_source<'a, T: Deserialize<'a>>(source: impl Source, backing: impl Sink + Into<&'a [u8]>) -> Result<T> {
+    T::deserialize(&mut Deserializer::from_source(source, backing)?)
 }
@@ -0,0 +1,48 @@
+//! A `#[serde(with = "serde_asn1_der::der_in_bit_string")]`-helper to encapsulate a DER-encoded value in a BIT STRING
+//! without unused bits (e.g. the `subjectPublicKey` of an X.509 `SubjectPublicKeyInfo`)
+//!
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! #[derive(Serialize, Deserialize)]
+//! struct RsaPublicKey {
+//!     modulus: u128,
+//!     exponent: u32
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct SubjectPublicKeyInfo {
+//!     algorithm: Vec<u8>,
+//!     #[serde(with = "serde_asn1_der::der_in_bit_string")]
+//!     subject_public_key: RsaPublicKey
+//! }
+//! ```
+
+use crate::BitString;
+use serde::{
+    de::{DeserializeOwned, Error as _},
+    ser::Error as _,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+/// Serializes `value` to DER and writes the encoding as BIT STRING
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let der = crate::to_vec(value).map_err(S::Error::custom)?;
+    BitString::with_bytes(&der, 0).map_err(S::Error::custom)?.serialize(serializer)
+}
+
+/// Reads a BIT STRING without unused bits and deserializes its contents from DER
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeOwned,
+    D: Deserializer<'de>,
+{
+    let bit_string = BitString::deserialize(deserializer)?;
+    if bit_string.unused_bits() != 0 {
+        return Err(D::Error::custom("The BIT STRING with encapsulated DER has unused bits"));
+    }
+    crate::from_bytes(bit_string.as_bytes()).map_err(D::Error::custom)
+}
@@ -0,0 +1,67 @@
+//! A `#[serde(with = "serde_asn1_der::der_in_octet_string")]`-helper to encapsulate a DER-encoded value in an
+//! OCTET STRING (e.g. the `extnValue` of an X.509 extension)
+//!
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! #[derive(Serialize, Deserialize)]
+//! struct BasicConstraints {
+//!     ca: bool
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Extension {
+//!     critical: bool,
+//!     #[serde(with = "serde_asn1_der::der_in_octet_string")]
+//!     value: BasicConstraints
+//! }
+//! ```
+//!
+//! With the `"serde_with"`-feature, `DerInOctetString<T>` offers the same as `#[serde_as(as = "DerInOctetString<T>")]`.
+
+use serde::{de::Error as _, ser::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde_with")]
+use std::marker::PhantomData;
+
+/// Serializes `value` to DER and writes the encoding as OCTET STRING
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    let der = crate::to_vec(value).map_err(S::Error::custom)?;
+    serializer.serialize_bytes(&der)
+}
+
+/// Reads an OCTET STRING and deserializes its contents from DER
+///
+/// _Note: The contents are borrowed from the input, so `T` can borrow from them too_
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let der = <&'de [u8]>::deserialize(deserializer)?;
+    crate::from_bytes(der).map_err(D::Error::custom)
+}
+
+/// A `serde_with`-adapter to encapsulate a DER-encoded `T` in an OCTET STRING
+#[cfg(feature = "serde_with")]
+pub struct DerInOctetString<T>(PhantomData<T>);
+#[cfg(feature = "serde_with")]
+impl<T: Serialize> serde_with::SerializeAs<T> for DerInOctetString<T> {
+    fn serialize_as<S>(source: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(source, serializer)
+    }
+}
+#[cfg(feature = "serde_with")]
+impl<'de, T: Deserialize<'de>> serde_with::DeserializeAs<'de, T> for DerInOctetString<T> {
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer)
+    }
+}
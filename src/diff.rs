@@ -0,0 +1,111 @@
+use asn1_der::DerObject;
+use std::fmt::{self, Display, Formatter};
+
+/// The first structural difference between two DER objects
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiffReport {
+    /// The element indices from the top-level object to the differing node
+    pub path: Vec<usize>,
+    /// The raw differing node in the first object or `None` if the first object has no such node
+    pub a: Option<Vec<u8>>,
+    /// The raw differing node in the second object or `None` if the second object has no such node
+    pub b: Option<Vec<u8>>,
+}
+impl DiffReport {
+    /// The tag of the differing node in the first object
+    pub fn tag_a(&self) -> Option<u8> {
+        self.a.as_ref()?.first().copied()
+    }
+    /// The tag of the differing node in the second object
+    pub fn tag_b(&self) -> Option<u8> {
+        self.b.as_ref()?.first().copied()
+    }
+}
+impl Display for DiffReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        /// Writes a node as hex string
+        fn write_node(node: &Option<Vec<u8>>, f: &mut Formatter<'_>) -> fmt::Result {
+            let node = match node {
+                Some(node) => node,
+                None => return write!(f, "<missing>"),
+            };
+            for (i, byte) in node.iter().enumerate() {
+                match i {
+                    0 => write!(f, "{:02x}", byte)?,
+                    _ => write!(f, " {:02x}", byte)?,
+                }
+            }
+            Ok(())
+        }
+
+        write!(f, "/")?;
+        for (i, index) in self.path.iter().enumerate() {
+            match i {
+                0 => write!(f, "{}", index)?,
+                _ => write!(f, "/{}", index)?,
+            }
+        }
+        write!(f, ": ")?;
+        write_node(&self.a, f)?;
+        write!(f, " != ")?;
+        write_node(&self.b, f)
+    }
+}
+
+/// Splits a constructed object into its subobjects or returns `None` if the value does not consist of DER objects
+fn subobjects<'a>(object: &DerObject<'a>) -> Option<Vec<DerObject<'a>>> {
+    // Only constructed objects have subobjects
+    if object.tag() & 0x20 == 0 {
+        return None;
+    }
+
+    let (value, mut objects, mut pos) = (object.value(), Vec::new(), 0);
+    while pos < value.len() {
+        let object = DerObject::decode_at(value, pos).ok()?;
+        pos += object.raw().len();
+        objects.push(object);
+    }
+    Some(objects)
+}
+
+/// Compares two objects recursively
+fn diff_objects(a: &DerObject, b: &DerObject, path: &mut Vec<usize>) -> Option<DiffReport> {
+    // Equal encodings have no differences
+    if a.raw() == b.raw() {
+        return None;
+    }
+    let report = |path: &[usize], a: Option<&DerObject>, b: Option<&DerObject>| DiffReport {
+        path: path.to_vec(),
+        a: a.map(|a| a.raw().to_vec()),
+        b: b.map(|b| b.raw().to_vec()),
+    };
+
+    // Descend into constructed objects with the same tag
+    let (children_a, children_b) = match (a.tag() == b.tag(), subobjects(a), subobjects(b)) {
+        (true, Some(children_a), Some(children_b)) => (children_a, children_b),
+        _ => return Some(report(path, Some(a), Some(b))),
+    };
+    for index in 0..children_a.len().max(children_b.len()) {
+        path.push(index);
+        let diff = match (children_a.get(index), children_b.get(index)) {
+            (Some(child_a), Some(child_b)) => diff_objects(child_a, child_b, path),
+            (child_a, child_b) => Some(report(path, child_a, child_b)),
+        };
+        if diff.is_some() {
+            return diff;
+        }
+        path.pop();
+    }
+    None
+}
+
+/// Compares the DER objects `a` and `b` and reports the first structural difference or `None` if both are equal
+///
+/// _Note: If one of the byte strings is not a valid DER object, the byte strings are compared as a whole_
+pub fn der_diff(a: &[u8], b: &[u8]) -> Option<DiffReport> {
+    match (DerObject::decode(a), DerObject::decode(b)) {
+        (Ok(a), Ok(b)) => diff_objects(&a, &b, &mut Vec::new()),
+        _ if a == b => None,
+        _ => Some(DiffReport { path: Vec::new(), a: Some(a.to_vec()), b: Some(b.to_vec()) }),
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{misc, validate_der, Result};
+use asn1_der::{DerObject, ErrorChain};
+use std::fmt::Write;
+
+/// Gets the display name of `tag` in the style of OpenSSL's `asn1parse`
+fn tag_name(tag: u8) -> String {
+    let number = tag & 0x1f;
+    let name = match (tag & 0xc0, number) {
+        (0x40, _) => return format!("appl [ {} ]", number),
+        (0x80, _) => return format!("cont [ {} ]", number),
+        (0xc0, _) => return format!("priv [ {} ]", number),
+        (_, 0x01) => "BOOLEAN",
+        (_, 0x02) => "INTEGER",
+        (_, 0x03) => "BIT STRING",
+        (_, 0x04) => "OCTET STRING",
+        (_, 0x05) => "NULL",
+        (_, 0x06) => "OBJECT",
+        (_, 0x09) => "REAL",
+        (_, 0x0a) => "ENUMERATED",
+        (_, 0x0c) => "UTF8STRING",
+        (_, 0x10) => "SEQUENCE",
+        (_, 0x11) => "SET",
+        (_, 0x12) => "NUMERICSTRING",
+        (_, 0x13) => "PRINTABLESTRING",
+        (_, 0x16) => "IA5STRING",
+        (_, 0x17) => "UTCTIME",
+        (_, 0x18) => "GENERALIZEDTIME",
+        (_, 0x1a) => "VISIBLESTRING",
+        (_, 0x1e) => "BMPSTRING",
+        (_, number) => return format!("univ [ {} ]", number),
+    };
+    name.to_string()
+}
+
+/// Formats the payload of the primitive object with `tag` as `:value` or returns an empty string for NULLs
+fn format_value(tag: u8, value: &[u8]) -> String {
+    let hex = |value: &[u8]| value.iter().map(|byte| format!("{:02X}", byte)).collect::<String>();
+    match tag {
+        0x01 => format!(":{}", value.first().copied().unwrap_or_default()),
+        0x02 | 0x0a => format!(":{}", hex(value)),
+        0x05 => String::new(),
+        0x06 => {
+            // Decode the base-128 subidentifiers and split the first one into the first two arcs
+            let mut arcs: Vec<u64> = Vec::new();
+            let mut arc = 0u64;
+            for byte in value {
+                arc = arc << 7 | (byte & 0x7f) as u64;
+                if byte & 0x80 == 0 {
+                    arcs.push(arc);
+                    arc = 0;
+                }
+            }
+            let first = arcs.first().map(|first| match first {
+                0..=39 => format!("0.{}", first),
+                40..=79 => format!("1.{}", first - 40),
+                first => format!("2.{}", first - 80),
+            });
+            let rest = arcs.iter().skip(1).map(u64::to_string);
+            format!(":{}", first.into_iter().chain(rest).collect::<Vec<_>>().join("."))
+        }
+        0x0c | 0x12 | 0x13 | 0x16 | 0x17 | 0x18 | 0x1a => format!(":{}", String::from_utf8_lossy(value)),
+        _ => format!(":[HEX DUMP]:{}", hex(value)),
+    }
+}
+
+/// Appends the lines for `object` at `offset` and its subobjects to `dump`
+fn dump_object(object: DerObject, offset: usize, depth: usize, dump: &mut String) -> Result<()> {
+    let (tag, header_len, value) = (object.tag(), object.header().len(), object.value());
+    let form = if tag & 0x20 != 0 { "cons" } else { "prim" };
+    let mut line =
+        format!("{:5}:d={:<2} hl={} l={:4} {}: {:<18}", offset, depth, header_len, value.len(), form, tag_name(tag));
+    if tag & 0x20 == 0 {
+        line.push_str(&format_value(tag, value));
+    }
+    let _ = writeln!(dump, "{}", line.trim_end());
+
+    // Dump the subobjects of constructed objects
+    let mut pos = 0;
+    while tag & 0x20 != 0 && pos < value.len() {
+        let subobject = misc::decode_object_at(value, pos).propagate(e!("Invalid subobject in constructed object"))?;
+        dump_object(subobject, offset + header_len + pos, depth + 1, dump)?;
+        pos += subobject.raw().len();
+    }
+    Ok(())
+}
+
+/// Dumps the DER object `bytes` as indented text tree in the style of OpenSSL's `asn1parse` (i.e. one line per object
+/// with the offset, the depth, the header length, the value length, the tag name and the primitive value)
+///
+/// _Note: Known primitive types are formatted as text; unknown ones as hex dump_
+pub fn asn1_dump(bytes: &[u8]) -> Result<String> {
+    // #implicit_validation: The structure is validated upfront, so the depth of the recursion is limited
+    validate_der(bytes)?;
+    let object = misc::decode_object_at(bytes, 0).propagate(e!("Failed to decode DER object"))?;
+
+    let mut dump = String::new();
+    dump_object(object, 0, 0, &mut dump)?;
+    Ok(dump)
+}
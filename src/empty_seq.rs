@@ -0,0 +1,37 @@
+use serde::{
+    de::{self, IgnoredAny, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt::{self, Formatter};
+
+/// An empty SEQUENCE (`30 00`)
+///
+/// _Note: Unlike `()`, which is mapped to NULL (`05 00`), this type is mapped to a SEQUENCE without elements_
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct EmptySeq;
+impl Serialize for EmptySeq {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_tuple(0)?.end()
+    }
+}
+impl<'de> Deserialize<'de> for EmptySeq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// A visitor for an empty sequence
+        struct EmptySeqVisitor;
+        impl<'de> Visitor<'de> for EmptySeqVisitor {
+            type Value = EmptySeq;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                write!(formatter, "an empty sequence")
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                match seq.next_element::<IgnoredAny>()? {
+                    None => Ok(EmptySeq),
+                    Some(_) => Err(de::Error::invalid_length(1, &self)),
+                }
+            }
+        }
+        deserializer.deserialize_tuple(0, EmptySeqVisitor)
+    }
+}
@@ -0,0 +1,43 @@
+//! A `#[serde(with = "serde_asn1_der::indexed")]`-helper to read a `SEQUENCE OF SEQUENCE { key, value }` (e.g. a list
+//! of attributes) into a `HashMap` that is indexed by the first element of each entry:
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! # use std::collections::HashMap;
+//! #[derive(Serialize, Deserialize)]
+//! struct Attributes {
+//!     #[serde(with = "serde_asn1_der::indexed")]
+//!     attributes: HashMap<u64, String>
+//! }
+//! ```
+//!
+//! _Note: Unlike the `"map"`-feature, this helper rejects duplicate keys. The entries are written in the iteration
+//! order of the map, which is unspecified for `HashMap`s._
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::HashMap, hash::Hash};
+
+/// Serializes the entries of `map` as SEQUENCE of two-element SEQUENCEs `{ key, value }`
+pub fn serialize<S, K, V>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    K: Serialize,
+    V: Serialize,
+{
+    serializer.collect_seq(map.iter())
+}
+
+/// Deserializes a SEQUENCE of two-element SEQUENCEs `{ key, value }` into a map indexed by the keys
+pub fn deserialize<'de, D, K, V>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+where
+    D: Deserializer<'de>,
+    K: Deserialize<'de> + Eq + Hash,
+    V: Deserialize<'de>,
+{
+    let entries = Vec::<(K, V)>::deserialize(deserializer)?;
+    let len = entries.len();
+    let map: HashMap<K, V> = entries.into_iter().collect();
+    match map.len() == len {
+        true => Ok(map),
+        false => Err(D::Error::custom("The sequence contains duplicate keys")),
+    }
+}
@@ -3,41 +3,123 @@
 
 #[macro_use]
 pub extern crate asn1_der;
+mod ber;
+pub mod bool_default_false;
 mod de;
+pub mod der_in_octet_string;
+mod diff;
+mod empty_seq;
+pub mod indexed;
 mod misc;
+pub mod range;
+mod real;
 mod ser;
+pub mod shared_bytes;
+mod validate;
 
 #[cfg(feature = "any")]
 mod any;
+#[cfg(feature = "const-oid")]
+pub mod const_oid;
+#[cfg(feature = "more_types")]
+pub mod der_in_bit_string;
+#[cfg(feature = "any")]
+mod dump;
+#[cfg(feature = "rfc3779")]
+pub mod rfc3779;
+#[cfg(feature = "more_types")]
+mod types;
 
 pub use crate::{
-    de::{from_bytes, from_reader, from_source},
-    ser::{to_sink, to_vec, to_writer},
+    de::{from_ber, from_bufread, from_bytes, from_reader, from_reader_iter, from_source, Deserializer, SequenceIter},
+    diff::{der_diff, DiffReport},
+    empty_seq::EmptySeq,
+    ser::{
+        encode_header, to_byte_buf_at, to_cursor, to_sink, to_slice, to_vec, to_vec_tagged, to_vec_with_depth_limit,
+        to_writer,
+    },
+    validate::validate_der,
 };
 
+#[cfg(feature = "serde_with")]
+pub use crate::der_in_octet_string::DerInOctetString;
+#[cfg(feature = "more_types")]
+pub use crate::types::{
+    named_bits, BigInteger, BitString, BmpString, ClassTagged, Explicit, GeneralizedTime, Ia5String, Implicit,
+    NumericString, ObjectIdentifier, PrintableString, Set, SetOf, SetOfRaw, Spanned, UtcTime, UtcTimeWindow,
+};
+#[cfg(all(feature = "more_types", feature = "any"))]
+pub use crate::types::{AlgorithmIdentifier, AlgorithmParameters};
 #[cfg(feature = "any")]
-pub use crate::any::AnyObject;
+pub use crate::{any::AnyObject, dump::asn1_dump, ser::sequence_with};
 
 pub use asn1_der::VecBacking;
 pub use serde;
 
-use asn1_der::Asn1DerError;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant};
 use std::{
     error::Error,
     fmt::{self, Display, Formatter},
 };
 
 /// A `serde_asn1_der` error
+///
+/// _Note: New variants may be added in the future, so `match`es outside of this crate need a wildcard arm_
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum SerdeAsn1DerError {
+    /// An error of the encoding itself (the variant is `InvalidData`, `Unsupported`, `InOutError` or `Other`)
     Asn1DerError(Asn1DerError),
+    /// A custom error raised by a `Serialize` or `Deserialize` implementation
     SerdeError(String),
+    /// An end-of-contents marker (tag `0x00`), which is only valid in indefinite-length BER encodings
+    UnexpectedEoc,
+    /// The nesting depth of constructed objects exceeds the limit
+    DepthLimitExceeded,
+    /// The declared length of an object exceeds the limit
+    LengthLimitExceeded,
+    /// The DER object is followed by trailing data
+    TrailingData,
+    /// A primitive type has a constructed encoding (contains the tag of the object)
+    ConstructedNotAllowed(u8),
+    /// The object has a different tag than the type expects
+    UnexpectedTag { expected: u8, found: u8 },
+    /// An error within a sequence (contains the dot-separated path to the failing element and the error)
+    AtPath(String, Box<SerdeAsn1DerError>),
+}
+impl SerdeAsn1DerError {
+    /// The kind of the underlying `Asn1DerError` if any (also if the error is wrapped in an `AtPath`)
+    pub fn asn1_der_variant(&self) -> Option<&Asn1DerErrorVariant> {
+        match self {
+            SerdeAsn1DerError::Asn1DerError(e) => Some(&e.error),
+            SerdeAsn1DerError::AtPath(_, e) => e.asn1_der_variant(),
+            _ => None,
+        }
+    }
+    /// Prepends `segment` to the path of the error
+    pub(crate) fn at(self, segment: &str) -> Self {
+        match self {
+            SerdeAsn1DerError::AtPath(path, e) => SerdeAsn1DerError::AtPath(format!("{}.{}", segment, path), e),
+            e => SerdeAsn1DerError::AtPath(segment.to_string(), Box::new(e)),
+        }
+    }
 }
 impl Display for SerdeAsn1DerError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             SerdeAsn1DerError::Asn1DerError(e) => e.fmt(f),
             SerdeAsn1DerError::SerdeError(s) => write!(f, "Serde error: {}", s),
+            SerdeAsn1DerError::UnexpectedEoc => write!(f, "Unexpected end-of-contents marker in DER data"),
+            SerdeAsn1DerError::DepthLimitExceeded => write!(f, "The nesting depth limit has been exceeded"),
+            SerdeAsn1DerError::LengthLimitExceeded => write!(f, "The object length limit has been exceeded"),
+            SerdeAsn1DerError::TrailingData => write!(f, "The DER object is followed by trailing data"),
+            SerdeAsn1DerError::ConstructedNotAllowed(tag) => {
+                write!(f, "The primitive type has a constructed encoding (tag 0x{:02x})", tag)
+            }
+            SerdeAsn1DerError::UnexpectedTag { expected, found } => {
+                write!(f, "The DER object has an unexpected tag (expected 0x{:02x}, found 0x{:02x})", expected, found)
+            }
+            SerdeAsn1DerError::AtPath(path, e) => write!(f, "{} (at `{}`)", e, path),
         }
     }
 }
@@ -45,6 +127,7 @@ impl Error for SerdeAsn1DerError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             SerdeAsn1DerError::Asn1DerError(e) => e.source(),
+            SerdeAsn1DerError::AtPath(_, e) => Some(e.as_ref()),
             _ => None,
         }
     }
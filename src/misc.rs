@@ -1,5 +1,5 @@
-use asn1_der::{Asn1DerError, ErrorChain, Sink, Source};
-use std::io::{self, ErrorKind::*, Read, Write};
+use asn1_der::{Asn1DerError, DerObject, ErrorChain, Sink, Source};
+use std::io::{self, BufRead, ErrorKind::*, Read, Write};
 
 /// Maps an `io::Error` to an `Asn1DerError`
 fn io_to_asn1_error(e: io::Error) -> Asn1DerError {
@@ -38,6 +38,17 @@ impl<T: Read> Source for ReaderSource<T> {
     }
 }
 
+/// A newtype wrapper around a `T: BufRead` that implements `Source` by reading directly from the buffer of `T`
+pub struct BufReadSource<T: BufRead>(pub T);
+impl<T: BufRead> Source for BufReadSource<T> {
+    fn read(&mut self) -> Result<u8, Asn1DerError> {
+        let buf = self.0.fill_buf().map_err(io_to_asn1_error).propagate(e!("Failed to fill the buffer"))?;
+        let next = *buf.first().ok_or(eio!("An I/O error occurred (\"UnexpectedEof\")"))?;
+        self.0.consume(1);
+        Ok(next)
+    }
+}
+
 /// A newtype wrapper around a `T: Write` that implements `Sink`
 pub struct WriterSink<T: Write>(pub T);
 impl<T: Write> Sink for WriterSink<T> {
@@ -45,3 +56,51 @@ impl<T: Write> Sink for WriterSink<T> {
         self.0.write_all(&[e]).map_err(io_to_asn1_error).propagate(e!("Failed to write byte to underlying sink"))
     }
 }
+
+/// The DER tag of a NumericString
+pub const NUMERIC_STRING_TAG: u8 = 0x12;
+/// The DER tag of a PrintableString
+pub const PRINTABLE_STRING_TAG: u8 = 0x13;
+/// The DER tag of an IA5String
+pub const IA5_STRING_TAG: u8 = 0x16;
+/// The DER tag of a VisibleString
+pub const VISIBLE_STRING_TAG: u8 = 0x1a;
+
+/// Checks whether `value` only consists of characters that are valid for the restricted string type with `tag`
+pub fn is_valid_string(tag: u8, value: &[u8]) -> bool {
+    match tag {
+        NUMERIC_STRING_TAG => value.iter().all(|b| b.is_ascii_digit() || *b == b' '),
+        PRINTABLE_STRING_TAG => value.iter().all(|b| b.is_ascii_alphanumeric() || b" '()+,-./:=?".contains(b)),
+        IA5_STRING_TAG => value.is_ascii(),
+        VISIBLE_STRING_TAG => value.iter().all(|b| (0x20..=0x7e).contains(b)),
+        _ => false,
+    }
+}
+
+/// Rejects tags in the high-tag-number form (i.e. tag numbers above 30), since `asn1_der` reads the tag as single byte
+/// and would misinterpret the subsequent tag bytes as length
+pub fn check_single_byte_tag(tag: u8) -> Result<(), Asn1DerError> {
+    match tag & 0x1f {
+        0x1f => Err(eunsupported!("Multi-byte tags are not supported by this implementation")),
+        _ => Ok(()),
+    }
+}
+/// Returns the total length of the object at `pos` in `bytes` without decoding it if the object has a single-byte tag
+/// other than end-of-contents and a short-form length and fits into `bytes`
+///
+/// _Note: This is a fast path for the common small objects; all other objects must be decoded with `decode_object_at`_
+pub fn short_object_len(bytes: &[u8], pos: usize) -> Option<usize> {
+    match bytes.get(pos..)? {
+        [tag, len, rest @ ..] if *tag != 0x00 && tag & 0x1f != 0x1f && *len < 0x80 && rest.len() >= *len as usize => {
+            Some(2 + *len as usize)
+        }
+        _ => None,
+    }
+}
+/// Decodes the DER object at `pos` in `bytes` after ensuring that it has a single-byte tag
+pub fn decode_object_at(bytes: &[u8], pos: usize) -> Result<DerObject<'_>, Asn1DerError> {
+    if let Some(tag) = bytes.get(pos) {
+        check_single_byte_tag(*tag)?;
+    }
+    DerObject::decode_at(bytes, pos)
+}
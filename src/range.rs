@@ -0,0 +1,31 @@
+//! A `#[serde(with = "serde_asn1_der::range")]`-helper to map a `Range<u32>` to a two-element sequence
+//!
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! # use std::ops::Range;
+//! #[derive(Serialize, Deserialize)]
+//! struct Allocation {
+//!     #[serde(with = "serde_asn1_der::range")]
+//!     ports: Range<u32>
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::Range;
+
+/// Serializes `range` as `SEQUENCE { start INTEGER, end INTEGER }`
+pub fn serialize<S>(range: &Range<u32>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    (range.start, range.end).serialize(serializer)
+}
+
+/// Deserializes a range from `SEQUENCE { start INTEGER, end INTEGER }`
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Range<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (start, end) = <(u32, u32)>::deserialize(deserializer)?;
+    Ok(start..end)
+}
@@ -0,0 +1,119 @@
+use crate::Result;
+use std::convert::TryFrom;
+
+/// The DER tag of a REAL
+pub(crate) const TAG: u8 = 0x09;
+
+/// The special values
+const PLUS_INFINITY: u8 = 0x40;
+const MINUS_INFINITY: u8 = 0x41;
+const NOT_A_NUMBER: u8 = 0x42;
+const MINUS_ZERO: u8 = 0x43;
+
+/// Splits a finite, non-zero `value` into an odd mantissa and an exponent so that `value = ±mantissa * 2^exponent`
+fn decompose(value: f64) -> (u64, i16) {
+    let bits = value.to_bits();
+    let (exponent, fraction) = ((bits >> 52) & 0x7ff, bits & ((1 << 52) - 1));
+    let (mantissa, exponent) = match exponent {
+        0 => (fraction, -1074),
+        exponent => (fraction | (1 << 52), exponent as i16 - 1075),
+    };
+
+    // Normalize the mantissa as required by DER
+    let shift = mantissa.trailing_zeros();
+    (mantissa >> shift, exponent + shift as i16)
+}
+
+/// Encodes `value` as DER-REAL value and returns the buffer together with the amount of used bytes
+///
+/// _Note: Finite non-zero values are encoded in the binary form with base 2 and an odd mantissa as required by DER_
+pub(crate) fn encode(value: f64) -> ([u8; 11], usize) {
+    let mut buf = [0; 11];
+    match value {
+        _ if value.is_nan() => buf[0] = NOT_A_NUMBER,
+        f64::INFINITY => buf[0] = PLUS_INFINITY,
+        f64::NEG_INFINITY => buf[0] = MINUS_INFINITY,
+        _ if value == 0.0 && value.is_sign_negative() => buf[0] = MINUS_ZERO,
+        _ if value == 0.0 => return (buf, 0),
+        _ => {
+            let (mantissa, exponent) = decompose(value);
+
+            // Write the info byte and the exponent with one byte if possible
+            let sign = if value.is_sign_negative() { 0x40 } else { 0x00 };
+            let mut len = match i8::try_from(exponent) {
+                Ok(exponent) => {
+                    buf[0] = 0x80 | sign;
+                    buf[1] = exponent as u8;
+                    2
+                }
+                Err(_) => {
+                    buf[0] = 0x80 | sign | 0x01;
+                    buf[1..3].copy_from_slice(&exponent.to_be_bytes());
+                    3
+                }
+            };
+
+            // Write the mantissa with the minimal amount of bytes
+            let mantissa = mantissa.to_be_bytes();
+            let skip = mantissa.iter().take_while(|byte| **byte == 0).count();
+            buf[len..len + 8 - skip].copy_from_slice(&mantissa[skip..]);
+            len += 8 - skip;
+            return (buf, len);
+        }
+    }
+    (buf, 1)
+}
+
+/// Decodes the DER-REAL `value`
+pub(crate) fn decode(value: &[u8]) -> Result<f64> {
+    let (info, rest) = match value.split_first() {
+        None => return Ok(0.0),
+        Some(split) => split,
+    };
+    match (*info, rest) {
+        (PLUS_INFINITY, []) => return Ok(f64::INFINITY),
+        (MINUS_INFINITY, []) => return Ok(f64::NEG_INFINITY),
+        (NOT_A_NUMBER, []) => return Ok(f64::NAN),
+        (MINUS_ZERO, []) => return Ok(-0.0),
+        (info, _) if info & 0x80 == 0 => Err(eunsupported!("Decimal reals are not supported by this implementation"))?,
+        (info, _) if info & 0x3c != 0 => {
+            Err(einval!("Non-canonical DER: the real has a base other than 2 or a scale"))?
+        }
+        _ => (),
+    }
+
+    // Read the exponent
+    let (exponent, mantissa) = match (info & 0x03, rest) {
+        (0x00, [e0, mantissa @ ..]) => (i64::from(*e0 as i8), mantissa),
+        (0x01, [e0, e1, mantissa @ ..]) if (*e0, *e1 & 0x80) != (0x00, 0x00) && (*e0, *e1 & 0x80) != (0xff, 0x80) => {
+            (i64::from(i16::from_be_bytes([*e0, *e1])), mantissa)
+        }
+        (0x00..=0x01, _) => Err(einval!("Invalid real exponent"))?,
+        _ => Err(eunsupported!("The real exponent is too large"))?,
+    };
+
+    // Read the mantissa
+    if mantissa.first() == Some(&0) || mantissa.last().map_or(true, |last| last & 1 == 0) {
+        Err(einval!("Non-canonical DER: the real mantissa is not minimal and odd"))?;
+    }
+    if mantissa.len() > 8 {
+        Err(eunsupported!("The real value cannot be represented as `f64`"))?;
+    }
+    let mantissa = mantissa.iter().fold(0u64, |num, byte| (num << 8) | *byte as u64);
+
+    // Compute the value and ensure that it is exact
+    let mut result = mantissa as f64;
+    for _ in 0..exponent.unsigned_abs().min(2048) {
+        result = match exponent.is_negative() {
+            true => result / 2.0,
+            false => result * 2.0,
+        };
+    }
+    if !result.is_finite() || result == 0.0 || decompose(result) != (mantissa, exponent as i16) {
+        Err(eunsupported!("The real value cannot be represented as `f64`"))?;
+    }
+    match info & 0x40 {
+        0 => Ok(result),
+        _ => Ok(-result),
+    }
+}
@@ -0,0 +1,165 @@
+//! Helper types for the IP address encodings of RFC 3779 (X.509 extensions for IP addresses)
+//!
+//! A prefix (`IPAddress`) is a BIT STRING that contains only the leading prefix bits of the address; a range
+//! (`IPAddressRange`) is a SEQUENCE of the lower and upper bound with the trailing zero or one bits removed. Since the
+//! encoding does not contain the address family, there are separate types for IPv4 and IPv6.
+//!
+//! ```rust
+//! # use serde_asn1_der::rfc3779::Ipv4Prefix;
+//! let prefix = Ipv4Prefix::new([10, 0, 0, 0].into(), 8).unwrap();
+//! assert_eq!(serde_asn1_der::to_vec(&prefix).unwrap(), b"\x03\x02\x00\x0a");
+//! ```
+
+use crate::{BitString, Result};
+use serde::{
+    de::Error as _,
+    ser::{Error as _, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// The `addressFamily` of an `IPAddressFamily` for IPv4
+pub const AFI_IPV4: [u8; 2] = [0x00, 0x01];
+/// The `addressFamily` of an `IPAddressFamily` for IPv6
+pub const AFI_IPV6: [u8; 2] = [0x00, 0x02];
+
+/// Encodes the `len` leading bits of `octets` as BIT STRING
+fn encode_bits(octets: &[u8], len: usize) -> Result<BitString> {
+    let mut bytes = octets[..len.div_ceil(8)].to_vec();
+    let unused_bits = ((8 - len % 8) % 8) as u8;
+
+    // Clear the unused bits since DER requires them to be zero
+    if let Some(last) = bytes.last_mut() {
+        *last &= 0xff << unused_bits;
+    }
+    BitString::with_bytes(&bytes, unused_bits)
+}
+/// Decodes `bit_string` into the leading bits of an address and sets the remaining bits to ones if `fill` is set
+fn decode_bits<const N: usize>(bit_string: &BitString, fill: bool) -> Result<[u8; N]> {
+    let bytes = bit_string.as_bytes();
+    if bytes.len() > N {
+        Err(einval!("The BIT STRING is longer than the address"))?;
+    }
+
+    // Copy the bits and fill the remainder
+    let mut octets = [if fill { 0xff } else { 0x00 }; N];
+    octets[..bytes.len()].copy_from_slice(bytes);
+    if let (true, Some(last)) = (fill, octets[..bytes.len()].last_mut()) {
+        *last |= !(0xff << bit_string.unused_bits());
+    }
+    Ok(octets)
+}
+
+/// Implements a prefix and a range type for an address type
+macro_rules! ip_types {
+    ($family:literal, $prefix:ident, $range:ident, $addr:ty, $int:ty, $octets:literal) => {
+        #[doc = concat!("An ", $family, " prefix (`IPAddress`), which is encoded as BIT STRING of the prefix bits")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $prefix {
+            /// The address
+            address: $addr,
+            /// The length of the prefix in bits
+            prefix_len: u8,
+        }
+        impl $prefix {
+            #[doc = concat!("Creates a new ", $family, " prefix")]
+            ///
+            /// _Note: The bits of `address` after the first `prefix_len` bits must be zero_
+            pub fn new(address: $addr, prefix_len: u8) -> Result<Self> {
+                if u32::from(prefix_len) > <$int>::BITS {
+                    Err(einval!("The prefix length is longer than the address"))?;
+                }
+                let mask = <$int>::MAX.checked_shr(u32::from(prefix_len)).unwrap_or(0);
+                if <$int>::from(address) & mask != 0 {
+                    Err(einval!("The address has bits set after the prefix"))?;
+                }
+                Ok(Self { address, prefix_len })
+            }
+
+            /// The address
+            pub fn address(&self) -> $addr {
+                self.address
+            }
+            /// The length of the prefix in bits
+            pub fn prefix_len(&self) -> u8 {
+                self.prefix_len
+            }
+        }
+        impl Serialize for $prefix {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let bit_string = encode_bits(&self.address.octets(), self.prefix_len as usize);
+                bit_string.map_err(S::Error::custom)?.serialize(serializer)
+            }
+        }
+        impl<'de> Deserialize<'de> for $prefix {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let bit_string = BitString::deserialize(deserializer)?;
+                let octets = decode_bits::<$octets>(&bit_string, false).map_err(D::Error::custom)?;
+                Ok(Self { address: octets.into(), prefix_len: bit_string.bit_len() as u8 })
+            }
+        }
+
+        #[doc = concat!("An ", $family, " range (`IPAddressRange`), which is encoded as SEQUENCE of the bounds")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $range {
+            /// The lowest address within the range
+            min: $addr,
+            /// The highest address within the range
+            max: $addr,
+        }
+        impl $range {
+            #[doc = concat!("Creates a new ", $family, " range from `min` to `max` (inclusive)")]
+            pub fn new(min: $addr, max: $addr) -> Result<Self> {
+                if min > max {
+                    Err(einval!("The lower bound of the range is greater than the upper bound"))?;
+                }
+                Ok(Self { min, max })
+            }
+
+            /// The lowest address within the range
+            pub fn min(&self) -> $addr {
+                self.min
+            }
+            /// The highest address within the range
+            pub fn max(&self) -> $addr {
+                self.max
+            }
+        }
+        impl Serialize for $range {
+            fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                // Remove the trailing zero bits of the lower and the trailing one bits of the upper bound
+                let min_len = <$int>::BITS - <$int>::from(self.min).trailing_zeros();
+                let max_len = <$int>::BITS - <$int>::from(self.max).trailing_ones();
+                let min = encode_bits(&self.min.octets(), min_len as usize).map_err(S::Error::custom)?;
+                let max = encode_bits(&self.max.octets(), max_len as usize).map_err(S::Error::custom)?;
+
+                let mut sequence = serializer.serialize_tuple(2)?;
+                sequence.serialize_element(&min)?;
+                sequence.serialize_element(&max)?;
+                sequence.end()
+            }
+        }
+        impl<'de> Deserialize<'de> for $range {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let (min, max) = <(BitString, BitString)>::deserialize(deserializer)?;
+                let min = decode_bits::<$octets>(&min, false).map_err(D::Error::custom)?;
+                let max = decode_bits::<$octets>(&max, true).map_err(D::Error::custom)?;
+                Self::new(min.into(), max.into()).map_err(D::Error::custom)
+            }
+        }
+    };
+}
+ip_types!("IPv4", Ipv4Prefix, Ipv4Range, Ipv4Addr, u32, 4);
+ip_types!("IPv6", Ipv6Prefix, Ipv6Range, Ipv6Addr, u128, 16);
@@ -1,7 +1,17 @@
-use crate::{misc::WriterSink, Result, SerdeAsn1DerError};
+#[cfg(feature = "more_types")]
+use crate::types::{self, Special};
+use crate::{
+    misc::{self, WriterSink},
+    real,
+    validate::{self, DEPTH_LIMIT},
+    Result, SerdeAsn1DerError,
+};
+#[cfg(feature = "more_types")]
+use asn1_der::DerObject;
 use asn1_der::{
-    typed::{DerEncodable, Null, OctetString, Sequence, Utf8String},
-    DerObject, ErrorChain, Sink,
+    der,
+    typed::{Boolean, DerTypeView, Integer, Null, OctetString, Sequence, Utf8String},
+    Asn1DerError, ErrorChain, Sink,
 };
 use serde::{
     ser::{
@@ -10,32 +20,209 @@ use serde::{
     },
     Serialize,
 };
-use std::io::Write;
+use std::{
+    convert::TryFrom,
+    io::{Cursor, Write},
+    ops::Range,
+};
+
+/// A sink that discards all bytes
+struct NullSink;
+impl Sink for NullSink {
+    fn write(&mut self, _e: u8) -> std::result::Result<(), Asn1DerError> {
+        Ok(())
+    }
+}
+
+/// A sink that writes into a slice and gives access to the written bytes
+struct SliceBuffer<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+impl<'a> SliceBuffer<'a> {
+    /// The underlying slice
+    fn buf(&mut self) -> &mut [u8] {
+        self.buf
+    }
+}
+impl<'a> Sink for SliceBuffer<'a> {
+    fn write(&mut self, e: u8) -> std::result::Result<(), Asn1DerError> {
+        let slot = self.buf.get_mut(self.pos).ok_or(eio!("The buffer is too small"))?;
+        *slot = e;
+        self.pos += 1;
+        Ok(())
+    }
+}
+
+/// A sink wrapper that tracks the amount of written bytes
+struct TrackingSink<'a, S: Sink> {
+    sink: &'a mut S,
+    pos: usize,
+}
+impl<'a, S: Sink> Sink for TrackingSink<'a, S> {
+    fn write(&mut self, e: u8) -> std::result::Result<(), Asn1DerError> {
+        self.sink.write(e).propagate(e!("Failed to write byte to underlying sink"))?;
+        self.pos = match self.pos.checked_add(1) {
+            Some(pos) => pos,
+            None => Err(eunsupported!("Cannot write more than `usize::max_value()` bytes"))?,
+        };
+        Ok(())
+    }
+}
+
+/// How the sequence lengths are determined
+enum LengthsMode {
+    /// A sizing pass that records the lengths
+    Record(Vec<usize>),
+    /// A writing pass that consumes the recorded lengths
+    Replay(Vec<usize>),
+    /// An allocation-free writing pass into a buffer that inserts each header in front of the written value
+    Insert,
+}
+
+/// The sequence lengths
+///
+/// Since the header of a sequence must be written before its elements, the lengths are computed in a sizing pass
+/// first and consumed in the same order during the writing pass. This way, a sequence can be streamed to the sink
+/// without buffering its elements. If the sink is a buffer, the header is written after the elements instead and then
+/// moved in front of them.
+struct Lengths {
+    mode: LengthsMode,
+    next: usize,
+}
+
+/// A replacement for the tag of the next object, which streams wrappers like IMPLICIT tags without buffering
+#[derive(Clone, Copy)]
+struct Retag {
+    /// The bits of the original tag that are kept
+    keep: u8,
+    /// The bits that are set
+    set: u8,
+    /// The tag of the restricted string type if the next object must be a string of this type
+    string: Option<u8>,
+}
+impl Retag {
+    /// Replaces the tag with `tag`
+    #[cfg(feature = "more_types")]
+    fn new(tag: u8) -> Self {
+        Self { keep: 0x00, set: tag, string: None }
+    }
+    /// Applies `self` and then `outer`
+    #[cfg(feature = "more_types")]
+    fn within(self, outer: Self) -> Self {
+        let (keep, set) = (self.keep & outer.keep, (self.set & outer.keep) | outer.set);
+        Self { keep, set, string: self.string.or(outer.string) }
+    }
+}
+
+/// The state of a constructed object that is being written
+struct Constructed {
+    tag: u8,
+    slot: usize,
+    start: usize,
+    /// The length written to the header during a writing pass
+    expected: Option<usize>,
+}
+impl Constructed {
+    /// Begins a new constructed object with `tag`
+    fn begin<S: Sink>(serializer: &mut Serializer<S>, tag: u8) -> Result<Self> {
+        // Limit the nesting depth to avoid a stack overflow on deeply recursive values
+        if serializer.depth >= serializer.depth_limit {
+            return Err(SerdeAsn1DerError::DepthLimitExceeded);
+        }
+        serializer.depth += 1;
+        let tag = serializer.take_tag(tag)?;
+
+        // Reserve the length slot or write the header
+        let lengths = &mut serializer.lengths;
+        let slot = lengths.next;
+        lengths.next += 1;
+        let expected = match &mut lengths.mode {
+            LengthsMode::Record(lengths) => {
+                lengths.push(0);
+                None
+            }
+            LengthsMode::Replay(lengths) => {
+                let len = lengths.get(slot).ok_or(eother!("The value changed between the serialization passes"))?;
+                Some(*len)
+            }
+            LengthsMode::Insert => None,
+        };
+        if let Some(len) = expected {
+            write_header(tag, len, &mut serializer.sink)?;
+        }
+
+        let start = serializer.sink.pos;
+        Ok(Self { tag, slot, start, expected })
+    }
+    /// Finalizes the constructed object
+    fn end<S: Sink>(self, serializer: &mut Serializer<S>) -> Result<()> {
+        serializer.depth -= 1;
+
+        // #implicit_validation: The position can only grow
+        let len = serializer.sink.pos - self.start;
+        match (self.expected, &mut serializer.lengths.mode) {
+            (Some(expected), _) if expected != len => {
+                Err(eother!("The value changed between the serialization passes"))?
+            }
+            (Some(_), _) => Ok(()),
+            (None, mode) => {
+                // Store the length and account for the header
+                if let LengthsMode::Record(lengths) = mode {
+                    lengths[self.slot] = len;
+                }
+                let end = serializer.sink.pos;
+                write_header(self.tag, len, &mut serializer.sink)?;
+
+                // Move the header in front of the value
+                if let (LengthsMode::Insert, Some(buffer)) = (mode, serializer.buffer) {
+                    buffer(serializer.sink.sink)[self.start..serializer.sink.pos]
+                        .rotate_right(serializer.sink.pos - end);
+                }
+                Ok(())
+            }
+        }
+    }
+}
 
 pub struct SequenceWriter<'a, 'r, S: Sink> {
     serializer: &'r mut Serializer<'a, S>,
-    objects: Vec<Vec<u8>>,
+    constructed: Constructed,
+    /// Whether a field has been omitted (i.e. was `None`)
+    omitted: bool,
 }
 impl<'a, 'r, S: Sink> SequenceWriter<'a, 'r, S> {
-    /// Writes the next `value` to the internal buffer
+    /// Begins a new constructed object with `tag`
+    fn new(serializer: &'r mut Serializer<'a, S>, tag: u8) -> Result<Self> {
+        let constructed = Constructed::begin(serializer, tag)?;
+        Ok(Self { serializer, constructed, omitted: false })
+    }
+    /// Writes the single object that is wrapped by an explicitly tagged object, which must not be omitted
     fn write_object<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-        let object = to_vec(value)?;
-        self.objects.push(object);
+        if self.serializer.write_omittable(value)? {
+            Err(eunsupported!("`None` cannot be written as explicitly tagged object"))?;
+        }
+        Ok(())
+    }
+    /// Writes the next element of a SEQUENCE OF or tuple, which must not be omitted
+    fn write_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        if self.serializer.write_omittable(value)? {
+            Err(eunsupported!("`None` cannot be written as element of a SEQUENCE OF or tuple"))?;
+        }
+        Ok(())
+    }
+    /// Writes the next struct field, where only trailing fields may be omitted
+    fn write_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        match self.serializer.write_omittable(value)? {
+            true => self.omitted = true,
+            false if self.omitted => Err(eunsupported!("`None` can only be written as trailing struct field"))?,
+            false => (),
+        }
         Ok(())
     }
     /// Finalizes the sequence
     fn finalize(self) -> Result<()> {
-        // Collect a list of DER objects
-        let objects: Result<_> = self.objects.iter().try_fold(Vec::new(), |mut vec, o| {
-            let object = DerObject::decode(o).propagate(e!("Failed to load constructed object"))?;
-            vec.push(object);
-            Ok(vec)
-        });
-
-        // Write sequence
-        let objects = objects?;
-        Sequence::write(&objects, self.serializer.sink).propagate(e!("Failed to write sequence"))?;
-        Ok(())
+        self.constructed.end(self.serializer)
     }
 }
 impl<'a, 'r, S: Sink> SerializeSeq for SequenceWriter<'a, 'r, S> {
@@ -43,7 +230,7 @@ impl<'a, 'r, S: Sink> SerializeSeq for SequenceWriter<'a, 'r, S> {
     type Error = SerdeAsn1DerError;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-        self.write_object(value)
+        self.write_element(value)
     }
     fn end(self) -> Result<Self::Ok> {
         self.finalize()
@@ -54,7 +241,7 @@ impl<'a, 'r, S: Sink> SerializeTuple for SequenceWriter<'a, 'r, S> {
     type Error = SerdeAsn1DerError;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-        self.write_object(value)
+        self.write_element(value)
     }
     fn end(self) -> Result<Self::Ok> {
         self.finalize()
@@ -65,7 +252,7 @@ impl<'a, 'r, S: Sink> SerializeStruct for SequenceWriter<'a, 'r, S> {
     type Error = SerdeAsn1DerError;
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, _key: &'static str, value: &T) -> Result<()> {
-        self.write_object(value)
+        self.write_field(value)
     }
     fn end(self) -> Result<Self::Ok> {
         self.finalize()
@@ -76,38 +263,58 @@ impl<'a, 'r, S: Sink> SerializeTupleStruct for SequenceWriter<'a, 'r, S> {
     type Error = SerdeAsn1DerError;
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
-        self.write_object(value)
+        self.write_element(value)
     }
     fn end(self) -> Result<Self::Ok> {
         self.finalize()
     }
 }
 
-/// A no-op struct for elements that require a key-value serialization
-struct KeyValueWriter;
-impl SerializeTupleVariant for KeyValueWriter {
+/// A writer for maps, which writes each entry as two-element SEQUENCE within a SEQUENCE
+pub struct MapWriter<'a, 'r, S: Sink> {
+    serializer: &'r mut Serializer<'a, S>,
+    constructed: Constructed,
+    /// The entry whose key has been written
+    entry: Option<Constructed>,
+}
+impl<'a, 'r, S: Sink> SerializeMap for MapWriter<'a, 'r, S> {
     type Ok = ();
     type Error = SerdeAsn1DerError;
 
-    fn serialize_field<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<()> {
-        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<()> {
+        let entry = Constructed::begin(self.serializer, Sequence::TAG)?;
+        if self.serializer.write_omittable(key)? {
+            Err(eunsupported!("`None` cannot be written as map key"))?;
+        }
+        self.entry = Some(entry);
+        Ok(())
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let entry = self.entry.take().ok_or(eother!("The map value has no key"))?;
+        if self.serializer.write_omittable(value)? {
+            Err(eunsupported!("`None` cannot be written as map value"))?;
+        }
+        entry.end(self.serializer)
     }
     fn end(self) -> Result<Self::Ok> {
-        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
+        if self.entry.is_some() {
+            Err(eother!("The map key has no value"))?;
+        }
+        self.constructed.end(self.serializer)
     }
 }
-impl SerializeMap for KeyValueWriter {
+
+/// A no-op struct for elements that require a key-value serialization
+struct KeyValueWriter;
+impl SerializeTupleVariant for KeyValueWriter {
     type Ok = ();
     type Error = SerdeAsn1DerError;
 
-    fn serialize_key<T: ?Sized + Serialize>(&mut self, _key: &T) -> Result<()> {
-        Err(eunsupported!("Map variants are not supported by this implementation"))?
-    }
-    fn serialize_value<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<()> {
-        Err(eunsupported!("Map variants are not supported by this implementation"))?
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<()> {
+        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
     }
     fn end(self) -> Result<Self::Ok> {
-        Err(eunsupported!("Map variants are not supported by this implementation"))?
+        Err(eunsupported!("Tuple variants are not supported by this implementation"))?
     }
 }
 impl SerializeStructVariant for KeyValueWriter {
@@ -124,7 +331,144 @@ impl SerializeStructVariant for KeyValueWriter {
 
 /// An ASN.1-DER serializer for `serde`
 struct Serializer<'a, S: Sink> {
-    sink: &'a mut S,
+    sink: TrackingSink<'a, S>,
+    lengths: Lengths,
+    /// The amount of currently open constructed objects
+    depth: usize,
+    /// The maximum amount of nested constructed objects
+    depth_limit: usize,
+    /// The position after the last NULL object
+    null_end: Option<usize>,
+    /// The replacement for the tag of the next object
+    retag: Option<Retag>,
+    /// Gives access to the written bytes if the sink is a buffer
+    buffer: Option<fn(&mut S) -> &mut [u8]>,
+}
+impl<'a, S: Sink> Serializer<'a, S> {
+    /// Creates a new serializer
+    fn new(sink: &'a mut S, mode: LengthsMode) -> Self {
+        let (sink, lengths) = (TrackingSink { sink, pos: 0 }, Lengths { mode, next: 0 });
+        Self { sink, lengths, depth: 0, depth_limit: DEPTH_LIMIT, null_end: None, retag: None, buffer: None }
+    }
+    /// Sets the maximum amount of nested constructed objects
+    fn with_depth_limit(mut self, depth_limit: usize) -> Self {
+        self.depth_limit = depth_limit;
+        self
+    }
+    /// Creates a serializer for the sizing pass
+    fn sizing(sink: &'a mut S) -> Self {
+        Self::new(sink, LengthsMode::Record(Vec::new()))
+    }
+    /// Creates a serializer for the writing pass with the `lengths` computed by the sizing pass
+    fn writing(sink: &'a mut S, lengths: Vec<usize>) -> Self {
+        Self::new(sink, LengthsMode::Replay(lengths))
+    }
+    /// Creates a serializer for a single writing pass into a buffer, where `buffer` gives access to the written bytes
+    fn inserting(sink: &'a mut S, buffer: fn(&mut S) -> &mut [u8]) -> Self {
+        Self { buffer: Some(buffer), ..Self::new(sink, LengthsMode::Insert) }
+    }
+
+    /// Writes `value` and returns whether it has been omitted (i.e. was `None`)
+    fn write_omittable<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<bool> {
+        let start = self.sink.pos;
+        value.serialize(&mut *self)?;
+        Ok(self.sink.pos == start)
+    }
+    /// Writes a NULL object
+    fn write_null(&mut self) -> Result<()> {
+        self.write_primitive(Null::TAG, &[])?;
+        self.null_end = Some(self.sink.pos);
+        Ok(())
+    }
+
+    /// Applies and clears the replacement for the tag of the next object, whose original tag is `tag`
+    fn take_tag(&mut self, tag: u8) -> Result<u8> {
+        match self.retag.take() {
+            Some(Retag { string: Some(_), .. }) => Err(einval!("A restricted string type requires a string value"))?,
+            Some(retag) => Ok((tag & retag.keep) | retag.set),
+            None => Ok(tag),
+        }
+    }
+    /// Writes `value` as string object after validating the character set of a restricted string type if any
+    fn write_string(&mut self, value: &str) -> Result<()> {
+        if let Some(retag @ Retag { string: Some(tag), .. }) = self.retag {
+            if !misc::is_valid_string(tag, value.as_bytes()) {
+                Err(einval!("String contains invalid characters for the string type"))?;
+            }
+            self.retag = Some(Retag { string: None, ..retag });
+        }
+        self.write_primitive(Utf8String::TAG, value.as_bytes())
+    }
+
+    /// Writes `value` as two's complement integer object with the minimal amount of bytes
+    fn write_integer(&mut self, tag: u8, value: i128) -> Result<()> {
+        // Skip the leading bytes that only repeat the sign
+        let bytes = value.to_be_bytes();
+        let redundant = bytes
+            .windows(2)
+            .take_while(|pair| matches!((pair[0], pair[1] & 0x80), (0x00, 0x00) | (0xff, 0x80)))
+            .count();
+        self.write_primitive(tag, &bytes[redundant..])
+    }
+    /// Writes a primitive object with `tag` and `value`
+    fn write_primitive(&mut self, tag: u8, value: &[u8]) -> Result<()> {
+        let tag = self.take_tag(tag)?;
+        write_header(tag, value.len(), &mut self.sink)?;
+        for byte in value {
+            self.sink.write(*byte).propagate(e!("Failed to write value"))?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "more_types")]
+impl<'a, S: Sink> Serializer<'a, S> {
+    /// Serializes `value` and replaces the tag of its object according to `retag`
+    fn write_retagged<T: ?Sized + Serialize>(&mut self, retag: Retag, value: &T) -> Result<()> {
+        // Apply nested replacements from the inside out
+        self.retag = Some(self.retag.map_or(retag, |outer| retag.within(outer)));
+        value.serialize(&mut *self)?;
+        match self.retag.take() {
+            Some(_) => Err(einval!("The value to retag does not produce an object"))?,
+            None => Ok(()),
+        }
+    }
+    /// Serializes the sequence `value` and writes it as SET (OF) with the elements sorted by their encoding
+    fn write_set_of<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        // Sorting does not change the length, so sizing passes write the set directly
+        let mode = match (&mut self.lengths.mode, self.buffer) {
+            (LengthsMode::Replay(lengths), _) => LengthsMode::Replay(std::mem::take(lengths)),
+            (LengthsMode::Insert, Some(buffer)) => {
+                // Sort the elements within the buffer
+                let start = self.sink.pos;
+                self.write_retagged(Retag::new(types::SET_TAG), value)?;
+                return sort_set(&mut buffer(self.sink.sink)[start..self.sink.pos]);
+            }
+            _ => return self.write_retagged(Retag::new(types::SET_TAG), value),
+        };
+
+        // Serialize the set with the lengths of the sizing pass and return the lengths afterwards
+        let mut buf = Vec::new();
+        let mut nested = Serializer::new(&mut buf, mode).with_depth_limit(self.depth_limit);
+        (nested.lengths.next, nested.depth) = (self.lengths.next, self.depth);
+        let result = nested.write_retagged(Retag::new(types::SET_TAG), value);
+        self.lengths.next = nested.lengths.next;
+        if let (LengthsMode::Replay(lengths), LengthsMode::Replay(nested)) =
+            (&mut self.lengths.mode, nested.lengths.mode)
+        {
+            *lengths = nested;
+        }
+        result?;
+
+        // Sort the elements and write the set
+        let set = DerObject::decode(&buf).propagate(e!("Failed to load serialized set"))?;
+        let mut objects = types::subobjects(set.value())?;
+        objects.sort_by(|a, b| a.raw().cmp(b.raw()));
+        write_header(self.take_tag(types::SET_TAG)?, set.value().len(), &mut self.sink)?;
+        for byte in objects.iter().flat_map(|o| o.raw()) {
+            self.sink.write(*byte).propagate(e!("Failed to write set"))?;
+        }
+        Ok(())
+    }
 }
 //noinspection RsTraitImplementation
 impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
@@ -135,7 +479,7 @@ impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
     type SerializeTuple = SequenceWriter<'a, 'r, S>;
     type SerializeTupleStruct = SequenceWriter<'a, 'r, S>;
     type SerializeTupleVariant = KeyValueWriter;
-    type SerializeMap = KeyValueWriter;
+    type SerializeMap = MapWriter<'a, 'r, S>;
     type SerializeStruct = SequenceWriter<'a, 'r, S>;
     type SerializeStructVariant = KeyValueWriter;
 
@@ -145,84 +489,100 @@ impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
     }
 
     fn serialize_bool(self, v: bool) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write boolean"))?)
+        self.write_primitive(Boolean::TAG, &[if v { 0xff } else { 0x00 }])
     }
 
-    fn serialize_i8(self, _v: i8) -> Result<Self::Ok> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok> {
+        self.write_integer(Integer::TAG, i128::from(v))
     }
-    fn serialize_i16(self, _v: i16) -> Result<Self::Ok> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok> {
+        self.write_integer(Integer::TAG, i128::from(v))
     }
-    fn serialize_i32(self, _v: i32) -> Result<Self::Ok> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok> {
+        self.write_integer(Integer::TAG, i128::from(v))
     }
-    fn serialize_i64(self, _v: i64) -> Result<Self::Ok> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok> {
+        self.write_integer(Integer::TAG, i128::from(v))
     }
     //noinspection RsTraitImplementation
-    fn serialize_i128(self, _v: i128) -> Result<Self::Ok> {
-        Err(eunsupported!("The object type is not supported by this implementation"))?
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok> {
+        self.write_integer(Integer::TAG, v)
     }
 
     //noinspection RsUnresolvedReference
     fn serialize_u8(self, v: u8) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write integer"))?)
+        self.write_integer(Integer::TAG, i128::from(v))
     }
     //noinspection RsUnresolvedReference
     fn serialize_u16(self, v: u16) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write integer"))?)
+        self.write_integer(Integer::TAG, i128::from(v))
     }
     //noinspection RsUnresolvedReference
     fn serialize_u32(self, v: u32) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write integer"))?)
+        self.write_integer(Integer::TAG, i128::from(v))
     }
     //noinspection RsUnresolvedReference
     fn serialize_u64(self, v: u64) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write integer"))?)
+        self.write_integer(Integer::TAG, i128::from(v))
     }
     //noinspection RsTraitImplementation
     fn serialize_u128(self, v: u128) -> Result<Self::Ok> {
-        Ok(v.encode(&mut self.sink).propagate(e!("Failed to write integer"))?)
+        match i128::try_from(v) {
+            Ok(v) => self.write_integer(Integer::TAG, v),
+            // Prepend a zero byte so that the value is not negative
+            Err(_) => {
+                let mut bytes = [0; 17];
+                bytes[1..].copy_from_slice(&v.to_be_bytes());
+                self.write_primitive(Integer::TAG, &bytes)
+            }
+        }
     }
 
-    fn serialize_f32(self, _v: f32) -> Result<Self::Ok> {
-        Err(eunsupported!("`f32`s are not supported by this implementation"))?
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok> {
+        self.serialize_f64(f64::from(v))
     }
-    fn serialize_f64(self, _v: f64) -> Result<Self::Ok> {
-        Err(eunsupported!("`f64`s are not supported by this implementation"))?
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok> {
+        let (buf, len) = real::encode(v);
+        self.write_primitive(real::TAG, &buf[..len])
     }
 
     //noinspection RsUnresolvedReference
     fn serialize_char(self, v: char) -> Result<Self::Ok> {
         let mut buf = [0; 4];
-        let v = v.encode_utf8(&mut buf);
-        Ok(Utf8String::write(v, &mut self.sink).propagate(e!("Failed to write UTF-8 string"))?)
+        self.write_string(v.encode_utf8(&mut buf))
     }
     fn serialize_str(self, v: &str) -> Result<Self::Ok> {
-        Ok(Utf8String::write(v, &mut self.sink).propagate(e!("Failed to write UTF-8 string"))?)
+        self.write_string(v)
     }
 
     fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok> {
-        Ok(OctetString::write(v, &mut self.sink).propagate(e!("Failed to write octet string"))?)
+        self.write_primitive(OctetString::TAG, v)
     }
 
     fn serialize_none(self) -> Result<Self::Ok> {
-        Ok(Null::write(&mut self.sink).propagate(e!("Failed to write null object"))?)
+        // An absent OPTIONAL is simply omitted
+        Ok(())
     }
     fn serialize_some<T: ?Sized + Serialize>(self, v: &T) -> Result<Self::Ok> {
-        v.serialize(self)
+        // Reject values that would be read back as `None`
+        let start = self.sink.pos;
+        let omitted = self.write_omittable(v)?;
+        if omitted || (self.null_end == Some(self.sink.pos) && self.sink.pos - start == 2) {
+            Err(eunsupported!("`Some` cannot contain a value that is encoded like `None` (e.g. `()`)"))?;
+        }
+        Ok(())
     }
 
     //noinspection RsUnresolvedReference
     fn serialize_unit(self) -> Result<Self::Ok> {
-        Ok(Null::write(&mut self.sink).propagate(e!("Failed to write null object"))?)
+        self.write_null()
     }
     //noinspection RsUnresolvedReference
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok> {
-        Ok(Null::write(&mut self.sink).propagate(e!("Failed to write null object"))?)
+        self.write_null()
     }
 
+    #[cfg(not(feature = "more_types"))]
     fn serialize_unit_variant(
         self,
         _name: &'static str,
@@ -231,23 +591,59 @@ impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
     ) -> Result<Self::Ok> {
         Err(eunsupported!("Unit variants are not supported by this implementation"))?
     }
+    #[cfg(feature = "more_types")]
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        variant_index: u32,
+        _variant: &'static str,
+    ) -> Result<Self::Ok> {
+        self.write_integer(types::ENUMERATED_TAG, i128::from(variant_index))
+    }
 
     fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok> {
+        #[cfg(feature = "more_types")]
+        match Special::for_name(_name) {
+            Some(Special::Primitive(tag)) => return self.write_retagged(Retag::new(tag), value),
+            Some(Special::RestrictedString(tag)) => {
+                return self.write_retagged(Retag { string: Some(tag), ..Retag::new(tag) }, value);
+            }
+            Some(Special::Set | Special::SetOf) => return self.write_set_of(value),
+            Some(Special::SetOfRaw) => return self.write_retagged(Retag::new(types::SET_TAG), value),
+            // Keep the constructed bit of the original tag
+            Some(Special::Implicit(number)) => {
+                return self.write_retagged(Retag { keep: 0x20, set: 0x80 | number, string: None }, value);
+            }
+            Some(Special::Explicit(tag)) => {
+                let mut writer = SequenceWriter::new(self, tag)?;
+                writer.write_object(value)?;
+                return writer.finalize();
+            }
+            Some(Special::Absent) => return Ok(()),
+            Some(Special::Spanned) | None => (),
+        }
         value.serialize(self)
     }
 
     fn serialize_newtype_variant<T: ?Sized + Serialize>(
         self,
         _name: &'static str,
-        _variant_index: u32,
+        variant_index: u32,
         _variant: &'static str,
-        _value: &T,
+        value: &T,
     ) -> Result<Self::Ok> {
-        Err(eunsupported!("Newtype variants are not supported by this implementation"))?
+        // Write the CHOICE as value that is explicitly tagged with the context-specific variant index
+        let tag = match variant_index {
+            index @ 0..=30 => 0xa0 | index as u8,
+            _ => Err(eunsupported!("CHOICEs with more than 31 variants are not supported by this implementation"))?,
+        };
+        let mut writer = SequenceWriter::new(self, tag)?;
+        writer.write_object(value)?;
+        writer.finalize()
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
-        Ok(SequenceWriter { serializer: self, objects: Vec::new() })
+        SequenceWriter::new(self, Sequence::TAG)
     }
     //noinspection RsUnresolvedReference
     fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
@@ -268,8 +664,14 @@ impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
         Err(eunsupported!("Tuple variants are not supported by this implementation"))?
     }
 
+    #[cfg(not(feature = "map"))]
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
-        Err(eunsupported!("Maps variants are not supported by this implementation"))?
+        Err(eunsupported!("Serializing maps requires the `map`-feature"))?
+    }
+    #[cfg(feature = "map")]
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        let constructed = Constructed::begin(self, Sequence::TAG)?;
+        Ok(MapWriter { serializer: self, constructed, entry: None })
     }
 
     //noinspection RsUnresolvedReference
@@ -288,17 +690,157 @@ impl<'a, 'r, S: Sink> serde::ser::Serializer for &'r mut Serializer<'a, S> {
     }
 }
 
+/// Sorts the elements of the encoded SET `set` by their encoding in place
+#[cfg(feature = "more_types")]
+fn sort_set(set: &mut [u8]) -> Result<()> {
+    let header_len = DerObject::decode(set).propagate(e!("Failed to load serialized set"))?.header().len();
+    let elements = &mut set[header_len..];
+
+    // Insert each element after the sorted elements that are not greater
+    let mut sorted = 0;
+    while sorted < elements.len() {
+        let len = misc::decode_object_at(elements, sorted).propagate(e!("Invalid subobject in set"))?.raw().len();
+        let mut pos = 0;
+        while pos < sorted {
+            let object = misc::decode_object_at(elements, pos).propagate(e!("Invalid subobject in set"))?;
+            if object.raw() > &elements[sorted..sorted + len] {
+                break;
+            }
+            pos += object.raw().len();
+        }
+        elements[pos..sorted + len].rotate_right(len);
+        sorted += len;
+    }
+    Ok(())
+}
+
+/// Writes a DER header with `tag` and `len` to `sink`
+fn write_header<S: Sink>(tag: u8, len: usize, sink: &mut S) -> Result<()> {
+    sink.write(tag).propagate(e!("Failed to write tag"))?;
+    der::length::encode(len, sink).propagate(e!("Failed to write length"))?;
+    Ok(())
+}
+
+/// Computes the sequence lengths of `value` and its total length
+fn size<T: ?Sized + Serialize>(value: &T, depth_limit: usize) -> Result<(Vec<usize>, usize)> {
+    let mut sink = NullSink;
+    let mut serializer = Serializer::sizing(&mut sink).with_depth_limit(depth_limit);
+    value.serialize(&mut serializer)?;
+    match serializer.lengths.mode {
+        LengthsMode::Record(lengths) => Ok((lengths, serializer.sink.pos)),
+        _ => unreachable!("The sizing pass records the lengths"),
+    }
+}
+/// Asserts in debug builds that `der` is a valid DER object to turn encoder bugs into loud test failures
+///
+/// _Note: The order of SET-elements is not checked since `SetOfRaw` may produce unsorted sets on purpose_
+fn debug_validate(der: &[u8], depth_limit: usize) {
+    // An absent top-level OPTIONAL produces no object at all
+    if cfg!(debug_assertions) && !der.is_empty() {
+        if let Err(e) = validate::validate_der_with_depth_limit(der, depth_limit) {
+            panic!("The serializer produced invalid DER ({})", e);
+        }
+    }
+}
+
 /// Serializes `value`
+///
+/// _Note: To avoid a stack overflow, constructed objects can be nested at most 64 levels deep_
 pub fn to_vec<T: ?Sized + Serialize>(value: &T) -> Result<Vec<u8>> {
-    let mut sink = Vec::new();
-    to_sink(value, &mut sink)?;
+    to_vec_with_depth_limit(value, DEPTH_LIMIT)
+}
+/// Serializes `value` where constructed objects can be nested at most `depth_limit` levels deep
+pub fn to_vec_with_depth_limit<T: ?Sized + Serialize>(value: &T, depth_limit: usize) -> Result<Vec<u8>> {
+    let (lengths, len) = size(value, depth_limit)?;
+    let mut sink = Vec::with_capacity(len);
+    value.serialize(&mut Serializer::writing(&mut sink, lengths).with_depth_limit(depth_limit))?;
+    debug_validate(&sink, depth_limit);
     Ok(sink)
 }
-/// Serializes `value` to `writer` and returns the amount of serialized bytes
+/// Encodes the DER header of an object with `tag` and a value of `len` bytes (e.g. to frame manually written values)
+pub fn encode_header(tag: u8, len: usize) -> Result<Vec<u8>> {
+    misc::check_single_byte_tag(tag)?;
+    let mut header = Vec::with_capacity(1 + std::mem::size_of::<usize>() + 1);
+    write_header(tag, len, &mut header)?;
+    Ok(header)
+}
+/// Serializes `value` and replaces the tag of the outermost object with `tag` (e.g. `0x31` to write a struct as SET)
+///
+/// _Note: `tag` must be constructed (`0x20`) if and only if the encoding of `value` is constructed_
+pub fn to_vec_tagged<T: ?Sized + Serialize>(value: &T, tag: u8) -> Result<Vec<u8>> {
+    misc::check_single_byte_tag(tag)?;
+    let mut der = to_vec(value)?;
+    match der.first_mut() {
+        Some(first) if tag != 0x00 && (*first ^ tag) & 0x20 == 0 => *first = tag,
+        _ => Err(einval!("The tag does not match the encoding of the value"))?,
+    }
+    Ok(der)
+}
+/// Serializes `value` by appending it to `buf` and returns the range the serialized object occupies in `buf`
+pub fn to_byte_buf_at<T: ?Sized + Serialize>(value: &T, buf: &mut Vec<u8>) -> Result<Range<usize>> {
+    let (lengths, len) = size(value, DEPTH_LIMIT)?;
+    let start = buf.len();
+    buf.reserve(len);
+
+    // Remove partially serialized objects on error
+    if let Err(e) = value.serialize(&mut Serializer::writing(buf, lengths)) {
+        buf.truncate(start);
+        return Err(e);
+    }
+    debug_validate(&buf[start..], DEPTH_LIMIT);
+    Ok(start..buf.len())
+}
+/// Serializes `value` into `buf` without allocating and returns the amount of bytes written
+///
+/// _Note: Instead of computing the sequence lengths upfront, each header is written after the elements and then moved
+/// in front of them, so `value` is serialized only once._
+pub fn to_slice<T: ?Sized + Serialize>(value: &T, buf: &mut [u8]) -> Result<usize> {
+    let mut sink = SliceBuffer { buf, pos: 0 };
+    value.serialize(&mut Serializer::inserting(&mut sink, SliceBuffer::buf))?;
+    Ok(sink.pos)
+}
+/// Serializes `value` to `writer`
+///
+/// _Note: `value` is streamed to `writer` without buffering. To compute the lengths of the sequences upfront, `value`
+/// is serialized twice, so its `Serialize`-implementation must be deterministic._
 pub fn to_writer<T: ?Sized + Serialize>(value: &T, writer: impl Write) -> Result<()> {
     to_sink(value, &mut WriterSink(writer))
 }
-/// Serializes `value` to `buf` and returns the amount of serialized bytes
+/// Serializes `value` at the current position of `cursor` and advances the position past the serialized object
+///
+/// _Note: Existing bytes after the current position are overwritten_
+pub fn to_cursor<T: ?Sized + Serialize>(value: &T, cursor: &mut Cursor<Vec<u8>>) -> Result<()> {
+    to_writer(value, cursor)
+}
+/// Serializes `value` to `sink`
+///
+/// _Note: `value` is streamed to `sink` without buffering. To compute the lengths of the sequences upfront, `value`
+/// is serialized twice, so its `Serialize`-implementation must be deterministic._
 pub fn to_sink<T: ?Sized + Serialize>(value: &T, mut sink: impl Sink) -> Result<()> {
-    value.serialize(&mut Serializer { sink: &mut sink })
+    let (lengths, _) = size(value, DEPTH_LIMIT)?;
+    value.serialize(&mut Serializer::writing(&mut sink, lengths))
+}
+
+/// A sequence of type-erased elements
+#[cfg(feature = "any")]
+struct ErasedSequence<'a>(&'a [&'a dyn erased_serde::Serialize]);
+#[cfg(feature = "any")]
+impl<'a> Serialize for ErasedSequence<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut sequence = serializer.serialize_tuple(self.0.len())?;
+        for element in self.0 {
+            sequence.serialize_element(element)?;
+        }
+        sequence.end()
+    }
+}
+/// Serializes `elements` as sequence in the given order
+///
+/// This is useful if the element order of a sequence differs from the field order of the Rust type.
+#[cfg(feature = "any")]
+pub fn sequence_with(elements: &[&dyn erased_serde::Serialize]) -> Result<Vec<u8>> {
+    to_vec(&ErasedSequence(elements))
 }
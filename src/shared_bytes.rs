@@ -0,0 +1,52 @@
+//! A `#[serde(with = "serde_asn1_der::shared_bytes")]`-helper to map shared byte buffers like `Arc<[u8]>` or
+//! `Rc<[u8]>` to an OCTET STRING
+//!
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! # use std::sync::Arc;
+//! #[derive(Serialize, Deserialize)]
+//! struct Blob {
+//!     #[serde(with = "serde_asn1_der::shared_bytes")]
+//!     data: Arc<[u8]>
+//! }
+//! ```
+//!
+//! _Note: Without this helper, `serde` serializes `Arc<[u8]>` as sequence of integers_
+
+use serde::{de::Visitor, Deserializer, Serializer};
+use std::fmt::{self, Formatter};
+
+/// Serializes `bytes` as OCTET STRING
+pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsRef<[u8]>,
+    S: Serializer,
+{
+    serializer.serialize_bytes(bytes.as_ref())
+}
+
+/// Deserializes an OCTET STRING into any type that can be created from a `Vec<u8>`
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: From<Vec<u8>>,
+    D: Deserializer<'de>,
+{
+    /// A visitor that collects the bytes of an OCTET STRING
+    struct BytesVisitor;
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+            write!(formatter, "an octet string")
+        }
+        fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+        fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+    }
+
+    let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+    Ok(T::from(bytes))
+}
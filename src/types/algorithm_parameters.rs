@@ -0,0 +1,119 @@
+use crate::{types::ABSENT_NAME, AnyObject, ObjectIdentifier, Result};
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt::{self, Formatter};
+
+/// The `parameters`-field of an X.509 `AlgorithmIdentifier`
+///
+/// Depending on the algorithm, the parameters are absent (e.g. ECDSA signatures), NULL (e.g. RSA) or an arbitrary
+/// object. `AlgorithmIdentifier` handles absent parameters already; to deserialize absent parameters in a custom
+/// struct, annotate the field with `#[serde(default)]`; e.g.
+/// ```rust
+/// # use serde_asn1_der::{AlgorithmParameters, ObjectIdentifier};
+/// # use serde_derive::{ Serialize, Deserialize };
+/// #[derive(Serialize, Deserialize)]
+/// struct AlgorithmIdentifier {
+///     algorithm: ObjectIdentifier,
+///     #[serde(default)]
+///     parameters: AlgorithmParameters
+/// }
+/// ```
+///
+/// _Note: Absent parameters are only omitted if they are the last element of a sequence; since elements are mapped
+/// by their position, absent parameters in the middle of a sequence cannot be read back_
+#[derive(Default)]
+pub enum AlgorithmParameters {
+    /// The parameters are absent
+    #[default]
+    None,
+    /// The parameters are NULL
+    Null,
+    /// The parameters are an arbitrary object
+    Any(Box<dyn AnyObject>),
+}
+impl Serialize for AlgorithmParameters {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AlgorithmParameters::None => serializer.serialize_newtype_struct(ABSENT_NAME, &()),
+            AlgorithmParameters::Null => serializer.serialize_unit(),
+            AlgorithmParameters::Any(object) => object.serialize(serializer),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for AlgorithmParameters {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // #implicit_validation: NULL is deserialized as `()`
+        let object = Box::<dyn AnyObject>::deserialize(deserializer)?;
+        match object.as_ref().as_any().is::<()>() {
+            true => Ok(AlgorithmParameters::Null),
+            false => Ok(AlgorithmParameters::Any(object)),
+        }
+    }
+}
+
+/// An X.509 `AlgorithmIdentifier`
+pub struct AlgorithmIdentifier {
+    /// The algorithm
+    pub algorithm: ObjectIdentifier,
+    /// The parameters
+    pub parameters: AlgorithmParameters,
+}
+impl AlgorithmIdentifier {
+    /// Creates a new algorithm identifier from the dotted `oid` and the DER encoded `params` if any
+    pub fn new(oid: &str, params: Option<Vec<u8>>) -> Result<Self> {
+        let algorithm = oid.parse()?;
+        let parameters = match params {
+            Some(params) => crate::from_bytes(&params)?,
+            None => AlgorithmParameters::None,
+        };
+        Ok(Self { algorithm, parameters })
+    }
+}
+impl Serialize for AlgorithmIdentifier {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut algorithm_identifier = serializer.serialize_struct("AlgorithmIdentifier", 2)?;
+        algorithm_identifier.serialize_field("algorithm", &self.algorithm)?;
+        algorithm_identifier.serialize_field("parameters", &self.parameters)?;
+        algorithm_identifier.end()
+    }
+}
+impl<'de> Deserialize<'de> for AlgorithmIdentifier {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct("AlgorithmIdentifier", &["algorithm", "parameters"], AlgorithmIdentifierVisitor)
+    }
+}
+
+/// A visitor for algorithm identifiers
+struct AlgorithmIdentifierVisitor;
+impl<'de> Visitor<'de> for AlgorithmIdentifierVisitor {
+    type Value = AlgorithmIdentifier;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "an algorithm identifier")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Absent parameters are only possible as the last element
+        let algorithm = seq.next_element()?.ok_or(A::Error::invalid_length(0, &self))?;
+        let parameters = seq.next_element()?.unwrap_or_default();
+        Ok(AlgorithmIdentifier { algorithm, parameters })
+    }
+}
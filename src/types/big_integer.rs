@@ -0,0 +1,81 @@
+use crate::{types::RawBytes, Result};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    ser::Error as _,
+    Deserialize, Serialize, Serializer,
+};
+use std::fmt::{self, Formatter};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::BigInteger";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x02;
+
+/// An ASN.1-INTEGER of arbitrary size as big-endian two's complement bytes (e.g. a 20-byte certificate serial number)
+///
+/// _Note: The bytes must be minimal (i.e. must not start with a `0x00` or `0xff` byte that only repeats the sign);
+/// serialization fails otherwise_
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigInteger(pub Vec<u8>);
+impl BigInteger {
+    /// Creates a new integer from its encoded form (i.e. the DER value without tag and length)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        match is_minimal(bytes) {
+            true => Ok(Self(bytes.to_vec())),
+            false => Err(einval!("Non-canonical DER: the integer is empty or not minimal"))?,
+        }
+    }
+}
+impl Serialize for BigInteger {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if !is_minimal(&self.0) {
+            return Err(S::Error::custom(eunsupported!("The integer is empty or not minimal")));
+        }
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&self.0))
+    }
+}
+impl<'de> Deserialize<'de> for BigInteger {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, BigIntegerVisitor)
+    }
+}
+
+/// Checks that `bytes` is not empty and has no leading byte that only repeats the sign
+fn is_minimal(bytes: &[u8]) -> bool {
+    match bytes {
+        [] => false,
+        [0x00, next, ..] => next & 0x80 != 0,
+        [0xff, next, ..] => next & 0x80 == 0,
+        _ => true,
+    }
+}
+
+/// A visitor for big integers
+struct BigIntegerVisitor;
+impl<'de> Visitor<'de> for BigIntegerVisitor {
+    type Value = BigInteger;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid integer")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        BigInteger::from_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
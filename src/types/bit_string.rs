@@ -0,0 +1,140 @@
+use crate::{types::RawBytes, Result};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::fmt::{self, Formatter};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::BitString";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x03;
+
+/// An ASN.1-BIT STRING
+///
+/// The bits are numbered from the most significant bit of the first byte on, as defined by ASN.1.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitString {
+    /// The encoded form; i.e. the amount of unused bits followed by the bytes
+    payload: Vec<u8>,
+}
+impl BitString {
+    /// Creates a new bit string from `bytes` where the `unused_bits` least significant bits of the last byte are not
+    /// part of the bit string
+    ///
+    /// _Note: DER requires the unused bits to be zero_
+    pub fn with_bytes(bytes: &[u8], unused_bits: u8) -> Result<Self> {
+        let mut payload = vec![unused_bits];
+        payload.extend_from_slice(bytes);
+        Self::from_payload(payload)
+    }
+    /// Creates a new bit string from the individual `bits`
+    pub fn with_bits(bits: &[bool]) -> Self {
+        // Pack the bits MSB-first
+        let mut payload = vec![0; 1 + bits.len().div_ceil(8)];
+        for (i, _) in bits.iter().enumerate().filter(|(_, bit)| **bit) {
+            payload[1 + i / 8] |= 0x80 >> (i % 8);
+        }
+
+        // Compute the amount of unused bits in the last byte
+        payload[0] = ((8 - bits.len() % 8) % 8) as u8;
+        Self { payload }
+    }
+    /// Creates a new bit string from the encoded form (i.e. the amount of unused bits followed by the bytes)
+    fn from_payload(payload: Vec<u8>) -> Result<Self> {
+        match payload.split_first() {
+            None => Err(einval!("The bit string is empty"))?,
+            Some((unused_bits, _)) if *unused_bits > 7 => Err(einval!("The amount of unused bits is greater than 7"))?,
+            Some((unused_bits, [])) if *unused_bits != 0 => {
+                Err(einval!("An empty bit string cannot have unused bits"))?
+            }
+            Some((unused_bits, [.., last])) if *last & !(0xff << unused_bits) != 0 => {
+                Err(einval!("Non-canonical DER: the unused bits are not zero"))?
+            }
+            Some(_) => Ok(Self { payload }),
+        }
+    }
+
+    /// The bytes of the bit string
+    ///
+    /// _Note: The unused bits of the last byte are included_
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.payload[1..]
+    }
+    /// The amount of unused bits in the last byte
+    pub fn unused_bits(&self) -> u8 {
+        self.payload[0]
+    }
+    /// The amount of bits
+    pub fn bit_len(&self) -> usize {
+        self.as_bytes().len() * 8 - self.unused_bits() as usize
+    }
+    /// Gets the bit at `index` or `false` if `index` is out of range
+    pub fn bit(&self, index: usize) -> bool {
+        match self.as_bytes().get(index / 8) {
+            Some(byte) if index < self.bit_len() => byte & (0x80 >> (index % 8)) != 0,
+            _ => false,
+        }
+    }
+    /// Sets the bit at `index` to `value`
+    ///
+    /// _Note: If `index` is out of range, the bit string is extended with zero bits_
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        // #implicit_validation: The unused bits are always zero, so the bit string can be extended with zero bits
+        if index >= self.bit_len() {
+            self.payload.resize(1 + (index + 1).div_ceil(8), 0);
+            self.payload[0] = ((8 - (index + 1) % 8) % 8) as u8;
+        }
+
+        // Set the bit MSB-first
+        match value {
+            true => self.payload[1 + index / 8] |= 0x80 >> (index % 8),
+            false => self.payload[1 + index / 8] &= !(0x80 >> (index % 8)),
+        }
+    }
+}
+impl Default for BitString {
+    fn default() -> Self {
+        Self { payload: vec![0] }
+    }
+}
+impl Serialize for BitString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&self.payload))
+    }
+}
+impl<'de> Deserialize<'de> for BitString {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, BitStringVisitor)
+    }
+}
+
+/// A visitor for bit strings
+struct BitStringVisitor;
+impl<'de> Visitor<'de> for BitStringVisitor {
+    type Value = BitString;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid bit string")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        BitString::from_payload(v.to_vec()).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
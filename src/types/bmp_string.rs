@@ -0,0 +1,76 @@
+use crate::{types::RawBytes, Result};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    ops::Deref,
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::BmpString";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x1e;
+
+/// An ASN.1-BMPString, which is encoded as UTF-16BE
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BmpString(pub String);
+impl BmpString {
+    /// Decodes a BMPString from its UTF-16BE encoded form
+    fn from_payload(payload: &[u8]) -> Result<Self> {
+        if payload.len() % 2 != 0 {
+            Err(einval!("The BMPString has an odd length"))?;
+        }
+        let units = payload.chunks_exact(2).map(|unit| u16::from_be_bytes([unit[0], unit[1]]));
+        let string: std::result::Result<String, _> = char::decode_utf16(units).collect();
+        Ok(Self(string.map_err(|_| einval!("The BMPString contains an unpaired surrogate"))?))
+    }
+}
+impl Deref for BmpString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Serialize for BmpString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let payload: Vec<u8> = self.0.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&payload))
+    }
+}
+impl<'de> Deserialize<'de> for BmpString {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, BmpStringVisitor)
+    }
+}
+
+/// A visitor for BMPStrings
+struct BmpStringVisitor;
+impl<'de> Visitor<'de> for BmpStringVisitor {
+    type Value = BmpString;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid BMPString")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        BmpString::from_payload(v).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
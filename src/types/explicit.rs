@@ -0,0 +1,119 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// The reserved newtype names indexed by the tag number
+pub(crate) const NAMES: [&str; 31] = tag_names!("Explicit");
+/// The reserved newtype names of APPLICATION-tagged values indexed by the tag number
+pub(crate) const APPLICATION_NAMES: [&str; 31] = tag_names!("Application");
+/// The reserved newtype names of PRIVATE-tagged values indexed by the tag number
+pub(crate) const PRIVATE_NAMES: [&str; 31] = tag_names!("Private");
+
+/// A value that is tagged with the context-specific tag `[N] EXPLICIT` (e.g. `[0] EXPLICIT Version`)
+///
+/// The value keeps its own tag and is wrapped into a constructed object with the context-specific tag `N`.
+///
+/// _Note: `N` must be at most 30 since multi-byte tags are not supported; and optional elements must be declared as
+/// `Option<Explicit<N, T>>`._
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Explicit<const N: u8, T>(pub T);
+impl<const N: u8, T> Explicit<N, T> {
+    /// The reserved newtype name, which fails to compile if `N` is out of range
+    const NAME: &'static str = NAMES[N as usize];
+}
+impl<const N: u8, T> Deref for Explicit<N, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<const N: u8, T> DerefMut for Explicit<N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<const N: u8, T: Serialize> Serialize for Explicit<N, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(Self::NAME, &self.0)
+    }
+}
+impl<'de, const N: u8, T: Deserialize<'de>> Deserialize<'de> for Explicit<N, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(Self::NAME, ExplicitVisitor(PhantomData)).map(Explicit)
+    }
+}
+
+/// A value that is explicitly tagged with the tag `N` of the class `CLASS` (i.e. `0b01` for APPLICATION, `0b10` for
+/// context-specific and `0b11` for PRIVATE; e.g. `[APPLICATION 5] EXPLICIT`)
+///
+/// The value keeps its own tag and is wrapped into a constructed object with the given class and tag number.
+///
+/// _Note: `CLASS` must not be universal (`0b00`) and `N` must be at most 30; `ClassTagged<0b10, N, T>` is equivalent to
+/// `Explicit<N, T>`_
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClassTagged<const CLASS: u8, const N: u8, T>(pub T);
+impl<const CLASS: u8, const N: u8, T> ClassTagged<CLASS, N, T> {
+    /// The reserved newtype name, which fails to compile if `CLASS` or `N` is out of range
+    const NAME: &'static str = match CLASS {
+        0b01 => APPLICATION_NAMES[N as usize],
+        0b10 => NAMES[N as usize],
+        0b11 => PRIVATE_NAMES[N as usize],
+        _ => panic!("The class must be APPLICATION, context-specific or PRIVATE"),
+    };
+}
+impl<const CLASS: u8, const N: u8, T> Deref for ClassTagged<CLASS, N, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<const CLASS: u8, const N: u8, T> DerefMut for ClassTagged<CLASS, N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<const CLASS: u8, const N: u8, T: Serialize> Serialize for ClassTagged<CLASS, N, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(Self::NAME, &self.0)
+    }
+}
+impl<'de, const CLASS: u8, const N: u8, T: Deserialize<'de>> Deserialize<'de> for ClassTagged<CLASS, N, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(Self::NAME, ExplicitVisitor(PhantomData)).map(ClassTagged)
+    }
+}
+
+/// A visitor for explicitly tagged values
+struct ExplicitVisitor<T>(PhantomData<T>);
+impl<'de, T: Deserialize<'de>> Visitor<'de> for ExplicitVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "an explicitly tagged value")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{types::RawBytes, Result};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    ser::Error as _,
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    ops::Range,
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::GeneralizedTime";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x18;
+
+/// The seconds per day
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// An ASN.1-GeneralizedTime as unix timestamp, which is encoded as `YYYYMMDDHHMMSSZ`
+///
+/// _Note: Only timestamps from year 0 to year 9999 can be encoded; fractional seconds are not supported_
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeneralizedTime(pub i64);
+impl GeneralizedTime {
+    /// Creates a new GeneralizedTime from its encoded form (i.e. the DER value without tag and length)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        // Validate the format
+        let digits = match bytes {
+            [digits @ .., b'Z'] if digits.len() == 14 && digits.iter().all(u8::is_ascii_digit) => digits,
+            _ => Err(einval!("The GeneralizedTime is not in the format YYYYMMDDHHMMSSZ"))?,
+        };
+        let number = |range: Range<usize>| parse_digits(&digits[range]);
+        let timestamp =
+            to_timestamp((number(0..4), number(4..6), number(6..8)), (number(8..10), number(10..12), number(12..14)))?;
+        Ok(Self(timestamp))
+    }
+
+    /// The encoded form (i.e. the DER value without tag and length)
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let ((year, month, day), (hour, minute, second)) = from_timestamp(self.0);
+        if !(0..=9999).contains(&year) {
+            Err(eunsupported!("The timestamp is out of range for a GeneralizedTime"))?;
+        }
+        Ok(format!("{:04}{:02}{:02}{:02}{:02}{:02}Z", year, month, day, hour, minute, second).into_bytes())
+    }
+}
+impl Serialize for GeneralizedTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.to_bytes().map_err(S::Error::custom)?;
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&bytes))
+    }
+}
+impl<'de> Deserialize<'de> for GeneralizedTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, GeneralizedTimeVisitor)
+    }
+}
+
+/// A visitor for GeneralizedTimes
+struct GeneralizedTimeVisitor;
+impl<'de> Visitor<'de> for GeneralizedTimeVisitor {
+    type Value = GeneralizedTime;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid GeneralizedTime")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        GeneralizedTime::from_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
+
+/// Parses the ASCII `digits` as decimal number
+pub(super) fn parse_digits(digits: &[u8]) -> i64 {
+    digits.iter().fold(0, |number, digit| number * 10 + (digit - b'0') as i64)
+}
+
+/// Converts a date and a time of day into a unix timestamp
+pub(super) fn to_timestamp(
+    (year, month, day): (i64, i64, i64),
+    (hour, minute, second): (i64, i64, i64),
+) -> Result<i64> {
+    if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+        Err(einval!("The time contains an invalid date"))?;
+    }
+    if hour > 23 || minute > 59 || second > 59 {
+        Err(einval!("The time contains an invalid time of day"))?;
+    }
+    Ok(days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second)
+}
+
+/// Converts a unix timestamp into a date and a time of day
+pub(super) fn from_timestamp(timestamp: i64) -> ((i64, i64, i64), (i64, i64, i64)) {
+    let (days, seconds) = (timestamp.div_euclid(SECONDS_PER_DAY), timestamp.rem_euclid(SECONDS_PER_DAY));
+    (civil_from_days(days), (seconds / 3600, seconds / 60 % 60, seconds % 60))
+}
+
+/// The amount of days in `month` of `year` in the proleptic Gregorian calendar
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts a date into the days since 1970-01-01 (see <https://howardhinnant.github.io/date_algorithms.html>)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    // Shift the year to start in March so that the leap day is the last day of the year
+    let year = if month <= 2 { year - 1 } else { year };
+    let (era, year_of_era) = (year.div_euclid(400), year.rem_euclid(400));
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts the days since 1970-01-01 into a date (see <https://howardhinnant.github.io/date_algorithms.html>)
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    // Shift the epoch to 0000-03-01 so that the leap day is the last day of the year
+    let days = days + 719_468;
+    let (era, day_of_era) = (days.div_euclid(146_097), days.rem_euclid(146_097));
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = (month_index + 2) % 12 + 1;
+    let year = era * 400 + year_of_era + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
@@ -0,0 +1,70 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// The reserved newtype names indexed by the tag number
+pub(crate) const NAMES: [&str; 31] = tag_names!("Implicit");
+
+/// A value that is tagged with the context-specific tag `[N] IMPLICIT` (e.g. `[0] IMPLICIT SEQUENCE OF INTEGER`)
+///
+/// The universal tag of the value is replaced by the context-specific tag `N`, which is constructed if the value is
+/// constructed.
+///
+/// _Note: `N` must be at most 30. Since CHOICEs cannot be tagged implicitly, `T` must not be an enum with newtype
+/// variants; and optional elements must be declared as `Option<Implicit<N, T>>`._
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Implicit<const N: u8, T>(pub T);
+impl<const N: u8, T> Implicit<N, T> {
+    /// The reserved newtype name, which fails to compile if `N` is out of range
+    const NAME: &'static str = NAMES[N as usize];
+}
+impl<const N: u8, T> Deref for Implicit<N, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<const N: u8, T> DerefMut for Implicit<N, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<const N: u8, T: Serialize> Serialize for Implicit<N, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(Self::NAME, &self.0)
+    }
+}
+impl<'de, const N: u8, T: Deserialize<'de>> Deserialize<'de> for Implicit<N, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(Self::NAME, ImplicitVisitor(PhantomData))
+    }
+}
+
+/// A visitor for implicitly tagged values
+struct ImplicitVisitor<const N: u8, T>(PhantomData<T>);
+impl<'de, const N: u8, T: Deserialize<'de>> Visitor<'de> for ImplicitVisitor<N, T> {
+    type Value = Implicit<N, T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "an implicitly tagged value")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Implicit)
+    }
+}
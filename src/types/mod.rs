@@ -0,0 +1,140 @@
+//! Additional ASN.1-DER types that have no native counterpart in the `serde` data model
+//!
+//! _Note: Each type serializes itself as a newtype struct with a reserved name. The name cannot collide with a Rust
+//! identifier, so the special handling never hijacks user types (unless they explicitly `#[serde(rename)]` themselves
+//! to a reserved name). Other `serde` formats treat the types as ordinary newtypes around their raw payload._
+
+/// Creates the reserved newtype names of a tagged type for the tag numbers 0 to 30
+macro_rules! tag_names {
+    ($type:literal) => (tag_names!($type; 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30));
+    ($type:literal; $($number:literal)*) => ([$(concat!("$serde_asn1_der::", $type, $number)),*]);
+}
+
+#[cfg(feature = "any")]
+mod algorithm_parameters;
+mod big_integer;
+mod bit_string;
+mod bmp_string;
+mod explicit;
+mod generalized_time;
+mod implicit;
+pub mod named_bits;
+mod oid;
+mod restricted_string;
+mod set;
+mod set_of;
+mod spanned;
+mod utc_time;
+
+#[cfg(feature = "any")]
+pub use crate::types::algorithm_parameters::{AlgorithmIdentifier, AlgorithmParameters};
+pub use crate::types::{
+    big_integer::BigInteger,
+    bit_string::BitString,
+    bmp_string::BmpString,
+    explicit::{ClassTagged, Explicit},
+    generalized_time::GeneralizedTime,
+    implicit::Implicit,
+    oid::ObjectIdentifier,
+    restricted_string::{Ia5String, NumericString, PrintableString},
+    set::Set,
+    set_of::{SetOf, SetOfRaw},
+    spanned::Spanned,
+    utc_time::{UtcTime, UtcTimeWindow},
+};
+use crate::{misc, Result};
+use asn1_der::{DerObject, ErrorChain};
+use serde::{Serialize, Serializer};
+
+/// The reserved newtype name of an absent OPTIONAL element
+pub(crate) const ABSENT_NAME: &str = "$serde_asn1_der::Absent";
+/// The DER tag of an ENUMERATED
+pub(crate) const ENUMERATED_TAG: u8 = 0x0a;
+/// The DER tag of a SET or SET OF
+pub(crate) const SET_TAG: u8 = 0x31;
+
+/// The special handling a type with a reserved newtype name requires
+pub(crate) enum Special {
+    /// A primitive object with the given tag; the payload is passed as raw bytes
+    Primitive(u8),
+    /// A restricted string with the given tag; the string is passed as `str` after validating the character set
+    RestrictedString(u8),
+    /// A SET; the elements are sorted by their encoding and assigned to the fields by their tags
+    Set,
+    /// A SET OF; the elements are sorted by their encoding
+    SetOf,
+    /// A SET OF; the elements are written and read in their given order
+    SetOfRaw,
+    /// An absent OPTIONAL element; nothing is written
+    Absent,
+    /// A value whose raw DER object is captured; the value and then the raw object are passed as sequence
+    Spanned,
+    /// A value with the context-specific tag with the given number (IMPLICIT tagging)
+    Implicit(u8),
+    /// A value that is wrapped into a constructed object with the given tag (EXPLICIT tagging)
+    Explicit(u8),
+}
+impl Special {
+    /// Gets the special handling for the reserved newtype `name` if `name` denotes a type of this module
+    pub fn for_name(name: &str) -> Option<Self> {
+        match name {
+            big_integer::NAME => Some(Special::Primitive(big_integer::TAG)),
+            bit_string::NAME => Some(Special::Primitive(bit_string::TAG)),
+            bmp_string::NAME => Some(Special::Primitive(bmp_string::TAG)),
+            generalized_time::NAME => Some(Special::Primitive(generalized_time::TAG)),
+            utc_time::NAME => Some(Special::Primitive(utc_time::TAG)),
+            oid::NAME => Some(Special::Primitive(oid::TAG)),
+            restricted_string::NUMERIC_STRING_NAME => Some(Special::RestrictedString(misc::NUMERIC_STRING_TAG)),
+            restricted_string::PRINTABLE_STRING_NAME => Some(Special::RestrictedString(misc::PRINTABLE_STRING_TAG)),
+            restricted_string::IA5_STRING_NAME => Some(Special::RestrictedString(misc::IA5_STRING_TAG)),
+            set::NAME => Some(Special::Set),
+            set_of::NAME => Some(Special::SetOf),
+            set_of::RAW_NAME => Some(Special::SetOfRaw),
+            ABSENT_NAME => Some(Special::Absent),
+            spanned::NAME => Some(Special::Spanned),
+            _ => {
+                // Look up the tag number in the names of the tagged types
+                let lookup = |names: &[&str]| names.iter().position(|tagged| *tagged == name).map(|n| n as u8);
+                let (application, private) = (lookup(&explicit::APPLICATION_NAMES), lookup(&explicit::PRIVATE_NAMES));
+                match (lookup(&implicit::NAMES), lookup(&explicit::NAMES), application, private) {
+                    (Some(number), ..) => Some(Special::Implicit(number)),
+                    (_, Some(number), ..) => Some(Special::Explicit(0xa0 | number)),
+                    (_, _, Some(number), _) => Some(Special::Explicit(0x60 | number)),
+                    (.., Some(number)) => Some(Special::Explicit(0xe0 | number)),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+/// Splits the constructed `value` into its subobjects
+pub(crate) fn subobjects(value: &[u8]) -> Result<Vec<DerObject<'_>>> {
+    let (mut objects, mut pos) = (Vec::new(), 0);
+    while pos < value.len() {
+        let object = misc::decode_object_at(value, pos).propagate(e!("Invalid subobject in constructed object"))?;
+        pos += object.raw().len();
+        objects.push(object);
+    }
+    Ok(objects)
+}
+
+/// Ensures that the subobjects of the SET `value` are sorted by their encoding as required by DER
+pub(crate) fn validate_set_order(value: &[u8]) -> Result<()> {
+    let objects = subobjects(value)?;
+    match objects.windows(2).all(|pair| pair[0].raw() <= pair[1].raw()) {
+        true => Ok(()),
+        false => Err(einval!("Non-canonical DER: the set elements are not sorted"))?,
+    }
+}
+
+/// A helper to serialize a byte slice via `serialize_bytes`
+struct RawBytes<'a>(&'a [u8]);
+impl<'a> Serialize for RawBytes<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(self.0)
+    }
+}
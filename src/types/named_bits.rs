@@ -0,0 +1,88 @@
+//! A `#[serde(with = "serde_asn1_der::named_bits")]`-helper to map a `u16`-flag set to a named bit list (i.e. a
+//! BIT STRING like X.509's `KeyUsage`)
+//!
+//! Bit `n` of the integer (counting from the least significant bit) maps to the named bit `n`:
+//! ```rust
+//! # use serde_derive::{ Serialize, Deserialize };
+//! #[derive(Serialize, Deserialize)]
+//! struct Extension {
+//!     /// `digitalSignature` (0), `keyEncipherment` (2), ...
+//!     #[serde(with = "serde_asn1_der::named_bits")]
+//!     key_usage: u16
+//! }
+//! ```
+//!
+//! With the `"bitflags"`-feature, `#[serde(with = "serde_asn1_der::named_bits::flags")]` maps flag types generated by
+//! the `bitflags`-crate of any unsigned width.
+
+use crate::types::BitString;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Encodes `flags` as named bit list with the trailing zero bits removed as required by DER
+fn encode(flags: u128) -> BitString {
+    let len = 128 - flags.leading_zeros() as usize;
+    let bits: Vec<bool> = (0..len).map(|index| flags & (1 << index) != 0).collect();
+    BitString::with_bits(&bits)
+}
+/// Decodes a named bit list or returns `None` if a bit at or after `width` is set
+fn decode(bit_string: &BitString, width: usize) -> Option<u128> {
+    if (width..bit_string.bit_len()).any(|index| bit_string.bit(index)) {
+        return None;
+    }
+    Some((0..width).filter(|index| bit_string.bit(*index)).fold(0, |flags, index| flags | 1 << index))
+}
+
+/// Serializes `flags` as named bit list with the trailing zero bits removed as required by DER
+pub fn serialize<S>(flags: &u16, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    encode(u128::from(*flags)).serialize(serializer)
+}
+
+/// Deserializes flags from a named bit list
+pub fn deserialize<'de, D>(deserializer: D) -> Result<u16, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let bit_string = BitString::deserialize(deserializer)?;
+    let flags = decode(&bit_string, 16).ok_or(D::Error::custom("The named bit list has more than 16 bits"))?;
+    Ok(flags as u16)
+}
+
+/// A `#[serde(with = "serde_asn1_der::named_bits::flags")]`-helper to map a flag type generated by the
+/// `bitflags`-crate to a named bit list
+///
+/// _Note: Bits without a defined flag are retained, so that unknown named bits survive a round trip_
+#[cfg(feature = "bitflags")]
+pub mod flags {
+    use super::{decode, encode};
+    use crate::types::BitString;
+    use bitflags::Flags;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::{convert::TryFrom, mem};
+
+    /// Serializes `flags` as named bit list with the trailing zero bits removed as required by DER
+    pub fn serialize<T, S>(flags: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Flags,
+        T::Bits: Into<u128>,
+        S: Serializer,
+    {
+        encode(flags.bits().into()).serialize(serializer)
+    }
+
+    /// Deserializes flags from a named bit list
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Flags,
+        T::Bits: TryFrom<u128>,
+        D: Deserializer<'de>,
+    {
+        let bit_string = BitString::deserialize(deserializer)?;
+        let bits = decode(&bit_string, 8 * mem::size_of::<T::Bits>())
+            .and_then(|bits| T::Bits::try_from(bits).ok())
+            .ok_or(D::Error::custom("The named bit list has more bits than the flag type"))?;
+        Ok(T::from_bits_retain(bits))
+    }
+}
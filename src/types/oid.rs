@@ -0,0 +1,164 @@
+use crate::{types::RawBytes, Result, SerdeAsn1DerError};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::ObjectIdentifier";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x06;
+
+/// An ASN.1-OBJECT IDENTIFIER
+///
+/// The identifier is stored in its encoded form (i.e. the DER value without tag and length), which is the same
+/// representation other OID crates expose. To use a `const_oid::ObjectIdentifier` directly, see the `const_oid`-helper
+/// (`"const-oid"`-feature).
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectIdentifier(Vec<u8>);
+impl ObjectIdentifier {
+    /// Creates a new object identifier from its encoded form (i.e. the DER value without tag and length)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        // Validate the encoding by decoding the arcs
+        if bytes.is_empty() {
+            Err(einval!("The object identifier is empty"))?;
+        }
+        for arc in Arcs(bytes) {
+            arc?;
+        }
+        Ok(Self(bytes.to_vec()))
+    }
+    /// Creates a new object identifier from its arcs (e.g. `[1, 2, 840, 113549]`)
+    ///
+    /// _Note: The first arc must be `0`, `1` or `2`, and the second arc must be less than `40` unless the first arc is
+    /// `2`_
+    pub fn from_arcs(arcs: &[u64]) -> Result<Self> {
+        // Pack the first two arcs into the first subidentifier
+        let first = match arcs {
+            [first @ 0..=1, second @ 0..=39, ..] => first * 40 + second,
+            [2, second, ..] => second.checked_add(80).ok_or(eunsupported!("The second arc is too large"))?,
+            [_, _, ..] => Err(einval!("The first two arcs are out of range"))?,
+            _ => Err(einval!("An object identifier needs at least two arcs"))?,
+        };
+
+        // Encode the subidentifiers as base-128 digits
+        let mut bytes = Vec::new();
+        for &arc in Some(&first).into_iter().chain(&arcs[2..]) {
+            let digits = (64 - arc.leading_zeros() as usize).max(1).div_ceil(7);
+            for digit in (0..digits).rev() {
+                let continuation = if digit > 0 { 0x80 } else { 0x00 };
+                bytes.push((arc >> (digit * 7)) as u8 & 0x7f | continuation);
+            }
+        }
+        Ok(Self(bytes))
+    }
+
+    /// The encoded form (i.e. the DER value without tag and length)
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+    /// The arcs of the object identifier
+    pub fn arcs(&self) -> Vec<u64> {
+        // #implicit_validation: The encoding has been validated on construction
+        let mut arcs: Vec<u64> = Arcs(&self.0).filter_map(|arc| arc.ok()).collect();
+
+        // Split the first subidentifier into the first two arcs
+        let (first, second) = match arcs[0] {
+            first if first < 40 => (0, first),
+            first if first < 80 => (1, first - 40),
+            first => (2, first - 80),
+        };
+        arcs[0] = second;
+        arcs.insert(0, first);
+        arcs
+    }
+}
+impl Display for ObjectIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let arcs: Vec<String> = self.arcs().iter().map(u64::to_string).collect();
+        write!(f, "{}", arcs.join("."))
+    }
+}
+impl FromStr for ObjectIdentifier {
+    type Err = SerdeAsn1DerError;
+
+    /// Parses an object identifier in dotted notation (e.g. `1.2.840.113549`)
+    fn from_str(s: &str) -> Result<Self> {
+        let arcs: std::result::Result<Vec<u64>, _> = s.split('.').map(u64::from_str).collect();
+        let arcs = arcs.map_err(|_| einval!("The object identifier is not in dotted notation"))?;
+        Self::from_arcs(&arcs)
+    }
+}
+impl Serialize for ObjectIdentifier {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&self.0))
+    }
+}
+impl<'de> Deserialize<'de> for ObjectIdentifier {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, ObjectIdentifierVisitor)
+    }
+}
+
+/// An iterator over the encoded subidentifiers
+struct Arcs<'a>(&'a [u8]);
+impl<'a> Iterator for Arcs<'a> {
+    type Item = Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Subidentifiers must be minimally encoded
+        match self.0.first() {
+            None => return None,
+            Some(0x80) => return Some(Err(einval!("Non-canonical DER: subidentifier has a leading zero byte").into())),
+            Some(_) => (),
+        }
+
+        // Collect the base-128 digits
+        let mut arc = 0u64;
+        while let Some((byte, rest)) = self.0.split_first() {
+            self.0 = rest;
+            arc = match arc.checked_mul(128) {
+                Some(arc) => arc | (byte & 0x7f) as u64,
+                None => return Some(Err(eunsupported!("The subidentifier is greater than `u64::max_value()`").into())),
+            };
+            if byte & 0x80 == 0 {
+                return Some(Ok(arc));
+            }
+        }
+        Some(Err(einval!("The last subidentifier is truncated").into()))
+    }
+}
+
+/// A visitor for object identifiers
+struct ObjectIdentifierVisitor;
+impl<'de> Visitor<'de> for ObjectIdentifierVisitor {
+    type Value = ObjectIdentifier;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid object identifier")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        ObjectIdentifier::from_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
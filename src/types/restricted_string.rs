@@ -0,0 +1,154 @@
+use crate::{misc, Result};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    ops::Deref,
+};
+
+/// The reserved newtype name of a NumericString
+pub(crate) const NUMERIC_STRING_NAME: &str = "$serde_asn1_der::NumericString";
+/// The reserved newtype name of a PrintableString
+pub(crate) const PRINTABLE_STRING_NAME: &str = "$serde_asn1_der::PrintableString";
+/// The reserved newtype name of an IA5String
+pub(crate) const IA5_STRING_NAME: &str = "$serde_asn1_der::Ia5String";
+
+/// An ASN.1-NumericString, which only consists of `0`-`9` and space
+///
+/// _Note: The character set is validated during serialization and deserialization_
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumericString(pub String);
+impl NumericString {
+    /// Creates a new NumericString from `string` if all characters are valid
+    pub fn new(string: impl Into<String>) -> Result<Self> {
+        let string = string.into();
+        match misc::is_valid_string(misc::NUMERIC_STRING_TAG, string.as_bytes()) {
+            true => Ok(Self(string)),
+            false => Err(einval!("String contains invalid characters for a NumericString"))?,
+        }
+    }
+}
+impl Deref for NumericString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Serialize for NumericString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(NUMERIC_STRING_NAME, &self.0)
+    }
+}
+impl<'de> Deserialize<'de> for NumericString {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NUMERIC_STRING_NAME, RestrictedStringVisitor(NumericString::new))
+    }
+}
+
+/// An ASN.1-PrintableString, which only consists of `A`-`Z`, `a`-`z`, `0`-`9`, space and `'()+,-./:=?`
+///
+/// _Note: The character set is validated during serialization and deserialization_
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PrintableString(pub String);
+impl PrintableString {
+    /// Creates a new PrintableString from `string` if all characters are valid
+    pub fn new(string: impl Into<String>) -> Result<Self> {
+        let string = string.into();
+        match misc::is_valid_string(misc::PRINTABLE_STRING_TAG, string.as_bytes()) {
+            true => Ok(Self(string)),
+            false => Err(einval!("String contains invalid characters for a PrintableString"))?,
+        }
+    }
+}
+impl Deref for PrintableString {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Serialize for PrintableString {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(PRINTABLE_STRING_NAME, &self.0)
+    }
+}
+impl<'de> Deserialize<'de> for PrintableString {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(PRINTABLE_STRING_NAME, RestrictedStringVisitor(PrintableString::new))
+    }
+}
+
+/// An ASN.1-IA5String, which only consists of 7-bit ASCII characters (e.g. for email addresses)
+///
+/// _Note: The character set is validated during serialization and deserialization_
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ia5String(pub String);
+impl Ia5String {
+    /// Creates a new IA5String from `string` if all characters are valid
+    pub fn new(string: impl Into<String>) -> Result<Self> {
+        let string = string.into();
+        match misc::is_valid_string(misc::IA5_STRING_TAG, string.as_bytes()) {
+            true => Ok(Self(string)),
+            false => Err(einval!("String contains invalid characters for an IA5String"))?,
+        }
+    }
+}
+impl Deref for Ia5String {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl Serialize for Ia5String {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(IA5_STRING_NAME, &self.0)
+    }
+}
+impl<'de> Deserialize<'de> for Ia5String {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(IA5_STRING_NAME, RestrictedStringVisitor(Ia5String::new))
+    }
+}
+
+/// A visitor for restricted strings that validates the string with the given constructor
+struct RestrictedStringVisitor<T>(fn(String) -> Result<T>);
+impl<'de, T> Visitor<'de> for RestrictedStringVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid restricted string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0(v.to_string()).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(self)
+    }
+}
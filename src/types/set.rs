@@ -0,0 +1,64 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::Set";
+
+/// An ASN.1-SET over the fields of a struct or tuple
+///
+/// _Note: As required by DER, the fields are sorted by their encoding during serialization. During deserialization,
+/// each element is assigned to the first remaining field that expects its tag, so the fields can be declared in any
+/// order as long as their tags are distinct. Absent `Option`-fields are read as `None`._
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Set<T>(pub T);
+impl<T> Deref for Set<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> DerefMut for Set<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T: Serialize> Serialize for Set<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, &self.0)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Set<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, SetVisitor(PhantomData))
+    }
+}
+
+/// A visitor for sets
+struct SetVisitor<T>(PhantomData<T>);
+impl<'de, T: Deserialize<'de>> Visitor<'de> for SetVisitor<T> {
+    type Value = Set<T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a set")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer).map(Set)
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{
+    de::{Deserializer, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::SetOf";
+/// The reserved newtype name of the unsorted variant
+pub(crate) const RAW_NAME: &str = "$serde_asn1_der::SetOfRaw";
+
+/// An ASN.1-SET OF
+///
+/// _Note: As required by DER, the elements are sorted by their encoding during serialization, so the element order is
+/// not preserved._
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SetOf<T>(pub Vec<T>);
+impl<T> Deref for SetOf<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> DerefMut for SetOf<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T: Serialize> Serialize for SetOf<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(NAME, &self.0)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SetOf<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, SetOfVisitor(PhantomData))
+    }
+}
+impl<T> From<Vec<T>> for SetOf<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+}
+
+/// An ASN.1-SET OF that preserves the element order
+///
+/// _Note: The elements are neither sorted during serialization nor is their order validated during deserialization,
+/// so unless the elements are already sorted by their encoding, this produces non-canonical DER. This is useful for
+/// presorted elements or for peers that depend on a specific order._
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SetOfRaw<T>(pub Vec<T>);
+impl<T> Deref for SetOfRaw<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl<T> DerefMut for SetOfRaw<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+impl<T: Serialize> Serialize for SetOfRaw<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(RAW_NAME, &self.0)
+    }
+}
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SetOfRaw<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(RAW_NAME, SetOfVisitor(PhantomData))
+    }
+}
+impl<T> From<Vec<T>> for SetOfRaw<T> {
+    fn from(elements: Vec<T>) -> Self {
+        Self(elements)
+    }
+}
+
+/// A visitor for sets
+struct SetOfVisitor<T, S>(PhantomData<(T, S)>);
+impl<'de, T: Deserialize<'de>, S: From<Vec<T>>> Visitor<'de> for SetOfVisitor<T, S> {
+    type Value = S;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a set")
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(S::from)
+    }
+}
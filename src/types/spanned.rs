@@ -0,0 +1,75 @@
+use serde::{
+    de::{Deserializer, Error, SeqAccess, Visitor},
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::Spanned";
+
+/// A deserialized value together with the raw DER object (including tag and length) it was decoded from
+///
+/// This is useful to verify signatures over a part of a structure; e.g. to get the exact encoding of the
+/// `tbsCertificate` of an X.509 certificate.
+///
+/// _Note: Only the value is serialized. If the value is deserialized from another format than ASN.1-DER, `raw` is
+/// empty._
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Spanned<'a, T> {
+    /// The deserialized value
+    pub value: T,
+    /// The raw DER object
+    pub raw: &'a [u8],
+}
+impl<'a, T> Deref for Spanned<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+impl<'a, T: Serialize> Serialize for Spanned<'a, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.value.serialize(serializer)
+    }
+}
+impl<'de: 'a, 'a, T: Deserialize<'de>> Deserialize<'de> for Spanned<'a, T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, SpannedVisitor(PhantomData))
+    }
+}
+
+/// A visitor for spanned values
+struct SpannedVisitor<'a, T>(PhantomData<(&'a (), T)>);
+impl<'de: 'a, 'a, T: Deserialize<'de>> Visitor<'de> for SpannedVisitor<'a, T> {
+    type Value = Spanned<'a, T>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a value and its raw DER object")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let value = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let raw = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        Ok(Spanned { value, raw })
+    }
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        Ok(Spanned { value, raw: &[] })
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    types::{
+        generalized_time::{from_timestamp, parse_digits, to_timestamp},
+        RawBytes,
+    },
+    Result,
+};
+use serde::{
+    de::{Deserializer, Error, Visitor},
+    ser::Error as _,
+    Deserialize, Serialize, Serializer,
+};
+use std::{
+    fmt::{self, Formatter},
+    ops::Range,
+};
+
+/// The reserved newtype name
+pub(crate) const NAME: &str = "$serde_asn1_der::UtcTime";
+/// The DER tag
+pub(crate) const TAG: u8 = 0x17;
+/// The first year of the RFC 5280 window
+const RFC5280_FIRST_YEAR: i64 = 1950;
+
+/// An ASN.1-UTCTime as unix timestamp, which is encoded as `YYMMDDHHMMSSZ`
+///
+/// The two-digit year is mapped to 1950 to 2049 according to RFC 5280, so only timestamps within this window can be
+/// encoded.
+///
+/// _Note: Besides the DER form, the forms `YYMMDDHHMMZ` and `YYMMDDHHMM[SS]±HHMM` are accepted during
+/// deserialization since they are still found in the wild_
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcTime(pub i64);
+impl UtcTime {
+    /// Creates a new UTCTime from its encoded form (i.e. the DER value without tag and length)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Self::from_bytes_in_window(bytes, RFC5280_FIRST_YEAR)
+    }
+    /// Creates a new UTCTime from its encoded form, mapping the two-digit year to `first_year..first_year + 100`
+    pub fn from_bytes_in_window(bytes: &[u8], first_year: i64) -> Result<Self> {
+        // Split the local time and the offset from UTC in seconds
+        let (digits, offset) = match bytes {
+            [digits @ .., b'Z'] => (digits, 0),
+            [digits @ .., sign @ (b'+' | b'-'), h1, h2, m1, m2] => {
+                let offset = [*h1, *h2, *m1, *m2];
+                let (hours, minutes) = (parse_digits(&offset[..2]), parse_digits(&offset[2..]));
+                if !offset.iter().all(u8::is_ascii_digit) || hours > 23 || minutes > 59 {
+                    Err(einval!("The UTCTime has an invalid offset"))?;
+                }
+                let offset = hours * 3600 + minutes * 60;
+                (digits, if *sign == b'+' { offset } else { -offset })
+            }
+            _ => Err(einval!("The UTCTime has an invalid time zone"))?,
+        };
+        if !matches!(digits.len(), 10 | 12) || !digits.iter().all(u8::is_ascii_digit) {
+            Err(einval!("The UTCTime is not in the format YYMMDDHHMM[SS]"))?;
+        }
+
+        // Expand the two-digit year into the window and default to zero seconds
+        let number = |range: Range<usize>| parse_digits(&digits[range]);
+        let year = first_year + (number(0..2) - first_year).rem_euclid(100);
+        let second = if digits.len() == 12 { number(10..12) } else { 0 };
+        let timestamp = to_timestamp((year, number(2..4), number(4..6)), (number(6..8), number(8..10), second))?;
+        Ok(Self(timestamp - offset))
+    }
+
+    /// The encoded form (i.e. the DER value without tag and length)
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        self.to_bytes_in_window(RFC5280_FIRST_YEAR)
+    }
+    /// The encoded form, which requires the year to be within `first_year..first_year + 100`
+    pub fn to_bytes_in_window(&self, first_year: i64) -> Result<Vec<u8>> {
+        let ((year, month, day), (hour, minute, second)) = from_timestamp(self.0);
+        if !(first_year..first_year + 100).contains(&year) {
+            Err(eunsupported!("The timestamp is out of range for an UTCTime"))?;
+        }
+        Ok(format!("{:02}{:02}{:02}{:02}{:02}{:02}Z", year % 100, month, day, hour, minute, second).into_bytes())
+    }
+}
+impl Serialize for UtcTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = self.to_bytes().map_err(S::Error::custom)?;
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&bytes))
+    }
+}
+impl<'de> Deserialize<'de> for UtcTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_newtype_struct(NAME, UtcTimeVisitor { first_year: RFC5280_FIRST_YEAR })
+    }
+}
+
+/// An ASN.1-UTCTime as unix timestamp, whose two-digit year is mapped to the window `FIRST_YEAR..FIRST_YEAR + 100`
+/// instead of the RFC 5280 window
+///
+/// _Note: `UtcTimeWindow<1950>` is equivalent to `UtcTime`_
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcTimeWindow<const FIRST_YEAR: i64>(pub i64);
+impl<const FIRST_YEAR: i64> Serialize for UtcTimeWindow<FIRST_YEAR> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let bytes = UtcTime(self.0).to_bytes_in_window(FIRST_YEAR).map_err(S::Error::custom)?;
+        serializer.serialize_newtype_struct(NAME, &RawBytes(&bytes))
+    }
+}
+impl<'de, const FIRST_YEAR: i64> Deserialize<'de> for UtcTimeWindow<FIRST_YEAR> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let UtcTime(timestamp) =
+            deserializer.deserialize_newtype_struct(NAME, UtcTimeVisitor { first_year: FIRST_YEAR })?;
+        Ok(Self(timestamp))
+    }
+}
+
+/// A visitor for UTCTimes
+struct UtcTimeVisitor {
+    /// The first year of the window for two-digit years
+    first_year: i64,
+}
+impl<'de> Visitor<'de> for UtcTimeVisitor {
+    type Value = UtcTime;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "a valid UTCTime")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        UtcTime::from_bytes_in_window(v, self.first_year).map_err(E::custom)
+    }
+
+    fn visit_newtype_struct<D>(self, deserializer: D) -> std::result::Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_bytes(self)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::{misc, Result, SerdeAsn1DerError};
+use asn1_der::ErrorChain;
+
+/// The maximum nesting depth of constructed objects
+pub(crate) const DEPTH_LIMIT: usize = 64;
+
+/// Validates that `bytes` is exactly one DER object with valid subobjects and a nesting depth of at most 64
+///
+/// _Note: The walk is iterative, so arbitrarily deep structures are rejected without risking a stack overflow_
+pub fn validate_der(bytes: &[u8]) -> Result<()> {
+    validate_der_with_depth_limit(bytes, DEPTH_LIMIT)
+}
+/// Validates that `bytes` is exactly one DER object with valid subobjects and a nesting depth of at most `depth_limit`
+pub(crate) fn validate_der_with_depth_limit(bytes: &[u8], depth_limit: usize) -> Result<()> {
+    let object = misc::decode_object_at(bytes, 0).propagate(e!("Failed to decode DER object"))?;
+    if object.raw().len() != bytes.len() {
+        return Err(SerdeAsn1DerError::TrailingData);
+    }
+
+    // Walk the tree using an explicit stack of `(value, position)`-pairs of the open constructed objects
+    let mut stack = Vec::new();
+    let mut next = Some(object);
+    loop {
+        // Descend into constructed objects
+        if let Some(object) = next.take() {
+            match object.tag() {
+                0x00 => return Err(SerdeAsn1DerError::UnexpectedEoc),
+                tag if tag & 0x20 == 0 => (),
+                _ if stack.len() >= depth_limit => return Err(SerdeAsn1DerError::DepthLimitExceeded),
+                _ => stack.push((object.value(), 0)),
+            }
+        }
+
+        // Load the next subobject of the innermost open object
+        let (value, pos) = match stack.last_mut() {
+            Some((value, pos)) if *pos < value.len() => (*value, pos),
+            Some(_) => {
+                stack.pop();
+                continue;
+            }
+            None => return Ok(()),
+        };
+        let object = misc::decode_object_at(value, *pos).propagate(e!("Invalid subobject in constructed object"))?;
+        *pos += object.raw().len();
+        next = Some(object);
+    }
+}
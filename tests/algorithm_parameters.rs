@@ -0,0 +1,71 @@
+#![cfg(all(feature = "any", feature = "more_types"))]
+
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec, AlgorithmParameters, ObjectIdentifier};
+
+#[derive(Serialize, Deserialize)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    #[serde(default)]
+    parameters: AlgorithmParameters,
+}
+
+#[test]
+fn test_null() {
+    // sha256WithRSAEncryption
+    let der = b"\x30\x0d\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x01\x0b\x05\x00";
+    let decoded: AlgorithmIdentifier = from_bytes(der).unwrap();
+    assert_eq!(decoded.algorithm.to_string(), "1.2.840.113549.1.1.11");
+    assert!(matches!(decoded.parameters, AlgorithmParameters::Null));
+    assert_eq!(to_vec(&decoded).unwrap(), der);
+}
+
+#[test]
+fn test_none() {
+    // ecdsa-with-SHA256
+    let der = b"\x30\x0a\x06\x08\x2a\x86\x48\xce\x3d\x04\x03\x02";
+    let decoded: AlgorithmIdentifier = from_bytes(der).unwrap();
+    assert_eq!(decoded.algorithm.to_string(), "1.2.840.10045.4.3.2");
+    assert!(matches!(decoded.parameters, AlgorithmParameters::None));
+    assert_eq!(to_vec(&decoded).unwrap(), der);
+}
+
+#[test]
+fn test_any() {
+    // id-dsa with (toy) domain parameters
+    let der = b"\x30\x14\x06\x07\x2a\x86\x48\xce\x38\x04\x01\x30\x09\x02\x01\x17\x02\x01\x0b\x02\x01\x04";
+    let decoded: AlgorithmIdentifier = from_bytes(der).unwrap();
+    assert_eq!(decoded.algorithm.to_string(), "1.2.840.10040.4.1");
+    match &decoded.parameters {
+        AlgorithmParameters::Any(object) => {
+            assert!(object.as_ref().as_any().is::<Vec<Box<dyn serde_asn1_der::AnyObject>>>())
+        }
+        _ => panic!("Invalid result"),
+    }
+    assert_eq!(to_vec(&decoded).unwrap(), der);
+}
+
+#[test]
+fn test_new() {
+    // rsaEncryption with NULL parameters
+    let identifier = serde_asn1_der::AlgorithmIdentifier::new("1.2.840.113549.1.1.1", Some(vec![0x05, 0x00])).unwrap();
+    assert!(matches!(identifier.parameters, AlgorithmParameters::Null));
+    let encoded = to_vec(&identifier).unwrap();
+    assert_eq!(encoded, b"\x30\x0d\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01\x05\x00");
+
+    let decoded: serde_asn1_der::AlgorithmIdentifier = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded.algorithm, identifier.algorithm);
+    assert!(matches!(decoded.parameters, AlgorithmParameters::Null));
+
+    // ecdsa-with-SHA256 without parameters
+    let identifier = serde_asn1_der::AlgorithmIdentifier::new("1.2.840.10045.4.3.2", None).unwrap();
+    let encoded = to_vec(&identifier).unwrap();
+    assert_eq!(encoded, b"\x30\x0a\x06\x08\x2a\x86\x48\xce\x3d\x04\x03\x02");
+    let decoded: serde_asn1_der::AlgorithmIdentifier = from_bytes(&encoded).unwrap();
+    assert!(matches!(decoded.parameters, AlgorithmParameters::None));
+
+    // Invalid OIDs and parameters
+    assert!(serde_asn1_der::AlgorithmIdentifier::new("1.2.x", None).is_err());
+    assert!(serde_asn1_der::AlgorithmIdentifier::new("1.2.840", Some(vec![0x05])).is_err());
+}
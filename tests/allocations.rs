@@ -0,0 +1,93 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_slice, to_vec};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+thread_local! {
+    /// The number of allocations performed by the current thread
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// An allocator that counts the allocations per thread
+struct CountingAllocator;
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_borrowed() {
+    let der = b"\x30\x17\x04\x04\x01\x02\x03\x04\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x02\x01\x07\x01\x01\xff";
+    let before = ALLOCATIONS.with(Cell::get);
+    let decoded: (&[u8], &str, u64, bool) = from_bytes(der).unwrap();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    assert_eq!(decoded, (&b"\x01\x02\x03\x04"[..], "Testolope", 7, true));
+    assert_eq!(after - before, 0, "Deserializing borrowed data from a slice must not allocate");
+}
+
+#[derive(Serialize)]
+struct Outer<'a> {
+    number: u64,
+    inner: (Inner<'a>, Inner<'a>),
+    flag: bool,
+}
+#[derive(Serialize)]
+struct Inner<'a> {
+    name: &'a str,
+    #[serde(with = "serde_bytes")]
+    bytes: &'a [u8],
+    option: Option<u8>,
+}
+
+#[test]
+fn test_to_slice() {
+    let inner = Inner { name: "Testolope", bytes: &[0x42; 200], option: None };
+    let outer = Outer { number: 7, inner: (inner, Inner { name: "", bytes: &[], option: Some(4) }), flag: true };
+
+    let mut buf = [0; 512];
+    let before = ALLOCATIONS.with(Cell::get);
+    let len = to_slice(&outer, &mut buf).unwrap();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    assert_eq!(buf[..len], *to_vec(&outer).unwrap());
+    assert_eq!(after - before, 0, "Serializing into a slice must not allocate");
+    assert!(to_slice(&outer, &mut [0; 16]).is_err());
+}
+
+#[cfg(feature = "more_types")]
+#[derive(Serialize)]
+struct Wrapped {
+    version: serde_asn1_der::Explicit<0, u8>,
+    name: serde_asn1_der::Implicit<1, serde_asn1_der::PrintableString>,
+    set: serde_asn1_der::SetOf<serde_asn1_der::SetOf<u64>>,
+}
+
+#[test]
+#[cfg(feature = "more_types")]
+fn test_to_slice_wrapped() {
+    use serde_asn1_der::{Explicit, Implicit, PrintableString, SetOf};
+    let wrapped = Wrapped {
+        version: Explicit(2),
+        name: Implicit(PrintableString("Testolope".to_string())),
+        set: SetOf(vec![SetOf(vec![0x0304, 7, 300]), SetOf(vec![]), SetOf(vec![1])]),
+    };
+
+    let mut buf = [0; 512];
+    let before = ALLOCATIONS.with(Cell::get);
+    let len = to_slice(&wrapped, &mut buf).unwrap();
+    let after = ALLOCATIONS.with(Cell::get);
+
+    assert_eq!(buf[..len], *to_vec(&wrapped).unwrap());
+    assert_eq!(after - before, 0, "Serializing wrappers into a slice must not allocate");
+}
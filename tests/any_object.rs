@@ -2,7 +2,7 @@
 
 #[macro_use]
 extern crate serde_derive;
-use serde_asn1_der::AnyObject;
+use serde_asn1_der::{encode_header, AnyObject, SerdeAsn1DerError::DepthLimitExceeded};
 
 /// A test vector
 #[derive(Deserialize)]
@@ -27,6 +27,64 @@ struct TestStruct {
     erased: Box<dyn AnyObject>,
 }
 
+/// A heterogeneous record with type-erased fields
+#[derive(Serialize)]
+struct ErasedRecord<'a> {
+    number: u8,
+    borrowed: &'a dyn erased_serde::Serialize,
+    boxed: Box<dyn erased_serde::Serialize>,
+    any: Box<dyn AnyObject>,
+}
+
+#[test]
+pub fn test_serialize_erased() {
+    let record = ErasedRecord {
+        number: 7,
+        borrowed: &"Testolope",
+        boxed: Box::new(vec![true]),
+        any: Box::new(serde_bytes::ByteBuf::from(b"\x01\x02".to_vec())),
+    };
+    let der = b"\x30\x17\x02\x01\x07\x0c\x09Testolope\x30\x03\x01\x01\xff\x04\x02\x01\x02";
+    assert_eq!(serde_asn1_der::to_vec(&record).unwrap(), der);
+}
+
+#[test]
+pub fn test_implicit_context_tag() {
+    // An IMPLICIT [0] primitive
+    let erased: Box<dyn AnyObject> = serde_asn1_der::from_bytes(b"\x80\x03\x01\x02\x03").unwrap();
+    assert_eq!(erased.as_ref().as_any().downcast_ref::<Vec<u8>>().unwrap(), b"\x01\x02\x03");
+}
+
+#[test]
+pub fn test_depth_limit() {
+    // 20,000 nested sequences
+    let mut headers = Vec::new();
+    let mut len = 0;
+    for _ in 0..20_000 {
+        let header = encode_header(0x30, len).unwrap();
+        len += header.len();
+        headers.push(header);
+    }
+    let der: Vec<u8> = headers.into_iter().rev().flatten().collect();
+    match serde_asn1_der::from_bytes::<Box<dyn AnyObject>>(&der) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+pub fn test_time() {
+    for (der, expected) in [
+        (&b"\x17\x0d\x32\x33\x30\x31\x30\x31\x31\x32\x30\x30\x30\x30\x5a"[..], "2023-01-01T12:00:00Z"),
+        (b"\x17\x0d\x39\x39\x31\x32\x33\x31\x32\x33\x35\x39\x35\x39\x5a", "1999-12-31T23:59:59Z"),
+        (b"\x18\x0f\x32\x30\x35\x30\x30\x31\x30\x31\x31\x32\x30\x30\x30\x30\x5a", "2050-01-01T12:00:00Z"),
+        (b"\x18\x11\x32\x30\x35\x30\x30\x31\x30\x31\x31\x32\x30\x30\x30\x30\x2e\x35\x5a", "2050-01-01T12:00:00.5Z"),
+    ] {
+        let erased: Box<dyn AnyObject> = serde_asn1_der::from_bytes(der).unwrap();
+        assert_eq!(erased.as_ref().as_any().downcast_ref::<String>().unwrap(), expected);
+    }
+}
+
 #[test]
 pub fn test() {
     for test in TestVector::load() {
@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData, VecBacking};
+use serde::Deserialize;
+use serde_asn1_der::{from_ber, from_bytes, Deserializer, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct TestStruct {
+    number: u8,
+    list: Vec<u8>,
+    string: String,
+}
+
+/// A SEQUENCE with indefinite length that contains a SEQUENCE OF with indefinite length
+const INDEFINITE: &[u8] = b"\x30\x80\x02\x01\x07\x30\x80\x02\x01\x04\x02\x01\x05\x00\x00\x0c\x01\x61\x00\x00";
+
+#[test]
+fn test() {
+    let plain = TestStruct { number: 7, list: vec![4, 5], string: "a".to_string() };
+    let mut backing = Vec::new();
+    assert_eq!(from_ber::<TestStruct>(INDEFINITE, VecBacking(&mut backing)).unwrap(), plain);
+    assert_eq!(backing, b"\x30\x0e\x02\x01\x07\x30\x06\x02\x01\x04\x02\x01\x05\x0c\x01\x61");
+
+    // DER is valid BER
+    let der = b"\x30\x0b\x02\x01\x07\x30\x03\x02\x01\x04\x0c\x01\x61";
+    let mut backing = Vec::new();
+    let decoded: TestStruct = from_ber(der, VecBacking(&mut backing)).unwrap();
+    assert_eq!(decoded, TestStruct { number: 7, list: vec![4], string: "a".to_string() });
+}
+
+#[test]
+fn test_err() {
+    // Indefinite lengths are not DER
+    match from_bytes::<TestStruct>(INDEFINITE) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A primitive object with indefinite length
+    match from_ber::<u8>(b"\x02\x80\x07\x00\x00", VecBacking(&mut Vec::new())) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A missing end-of-contents marker
+    assert!(from_ber::<TestStruct>(&INDEFINITE[..INDEFINITE.len() - 2], VecBacking(&mut Vec::new())).is_err());
+}
+
+#[test]
+fn test_bool() {
+    // Booleans are strict unless lenient booleans are enabled
+    match from_ber::<bool>(b"\x01\x01\x01", VecBacking(&mut Vec::new())) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // BER allows any non-zero value for `true`
+    for (ber, bool) in [(&b"\x01\x01\x01"[..], true), (b"\x01\x01\xff", true), (b"\x01\x01\x00", false)] {
+        let mut backing = Vec::new();
+        let mut deserializer = Deserializer::from_ber(ber, VecBacking(&mut backing)).unwrap();
+        deserializer.set_lenient_bool(true);
+        assert_eq!(bool::deserialize(&mut deserializer).unwrap(), bool);
+    }
+}
+
+#[test]
+fn test_lengths() {
+    // DER requires minimal lengths
+    for der in [&b"\x04\x81\x01\x07"[..], b"\x04\x82\x00\x01\x07"] {
+        match from_bytes::<&[u8]>(der) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+        let mut backing = Vec::new();
+        assert_eq!(from_ber::<&[u8]>(der, VecBacking(&mut backing)).unwrap(), b"\x07");
+    }
+
+    // Zero-padded long-form lengths are read as their value
+    let der = b"\x04\x82\x00\x05\x01\x02\x03\x04\x05";
+    assert!(from_bytes::<&[u8]>(der).is_err());
+    let mut backing = Vec::new();
+    let deserializer = Deserializer::from_ber(der, VecBacking(&mut backing)).unwrap();
+    assert_eq!(deserializer.content_borrowed(), b"\x01\x02\x03\x04\x05");
+
+    // A long-form length is required from 128 on
+    let der = [&b"\x04\x81\x80"[..], &[0x42; 128]].concat();
+    assert_eq!(from_bytes::<&[u8]>(&der).unwrap(), [0x42; 128]);
+    match from_bytes::<TestStruct>(b"\x30\x81\x7f") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "more_types")]
+
+use serde_asn1_der::{from_bytes, to_vec, BigInteger, SerdeAsn1DerError::SerdeError};
+
+#[test]
+fn test() {
+    // A 20-byte serial number, a negative number and zero
+    let serial = [&b"\x7f"[..], &[0x42; 19]].concat();
+    for (plain, der) in [
+        (BigInteger(serial.clone()), [&b"\x02\x14"[..], &serial].concat()),
+        (BigInteger(b"\x00\x80".to_vec()), b"\x02\x02\x00\x80".to_vec()),
+        (BigInteger(b"\xff\x7f".to_vec()), b"\x02\x02\xff\x7f".to_vec()),
+        (BigInteger(b"\x00".to_vec()), b"\x02\x01\x00".to_vec()),
+    ] {
+        assert_eq!(to_vec(&plain).unwrap(), der);
+        assert_eq!(from_bytes::<BigInteger>(&der).unwrap(), plain);
+    }
+}
+
+#[test]
+fn test_err() {
+    // Non-minimal and empty integers cannot be serialized
+    for bytes in [&b"\x00\x01"[..], b"\xff\xff", b"\x00\x00\x80", b""] {
+        match to_vec(&BigInteger(bytes.to_vec())) {
+            Err(SerdeError(_)) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    // Non-minimal and empty integers are rejected during deserialization
+    for der in [&b"\x02\x02\x00\x01"[..], b"\x02\x02\xff\xff", b"\x02\x00"] {
+        match from_bytes::<BigInteger>(der) {
+            Err(SerdeError(_)) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
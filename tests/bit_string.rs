@@ -0,0 +1,70 @@
+#![cfg(feature = "more_types")]
+
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{from_bytes, to_vec, BitString, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[test]
+fn test_bits() {
+    // KeyUsage with `digitalSignature` (0) and `keyEncipherment` (2)
+    let plain = BitString::with_bits(&[true, false, true]);
+    assert_eq!(plain.unused_bits(), 5);
+    assert_eq!(plain.as_bytes(), b"\xa0");
+    assert!(plain.bit(0) && !plain.bit(1) && plain.bit(2));
+    assert!(!plain.bit(3) && !plain.bit(64));
+
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x03\x02\x05\xa0");
+
+    let decoded: BitString = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+
+    // Full bytes and no bits at all
+    assert_eq!(to_vec(&BitString::with_bits(&[true; 8])).unwrap(), b"\x03\x02\x00\xff");
+    assert_eq!(to_vec(&BitString::with_bits(&[])).unwrap(), b"\x03\x01\x00");
+}
+
+#[test]
+fn test_set_bit() {
+    // KeyUsage with `digitalSignature` (0) and `keyAgreement` (5)
+    let mut plain = BitString::default();
+    assert_eq!(plain.bit_len(), 0);
+    plain.set_bit(5, true);
+    plain.set_bit(0, true);
+    assert_eq!((plain.bit_len(), plain.unused_bits()), (6, 2));
+    assert_eq!(plain.as_bytes(), b"\x84");
+    assert!(plain.bit(0) && plain.bit(5) && !plain.bit(1));
+    assert_eq!(to_vec(&plain).unwrap(), b"\x03\x02\x02\x84");
+
+    // Clearing bits keeps the length; extending past a byte boundary keeps the existing bits
+    plain.set_bit(5, false);
+    assert_eq!((plain.bit_len(), plain.as_bytes()), (6, &b"\x80"[..]));
+    let mut plain = BitString::with_bytes(b"\x84", 2).unwrap();
+    plain.set_bit(8, true);
+    assert_eq!((plain.bit_len(), plain.unused_bits(), plain.as_bytes()), (9, 7, &b"\x84\x80"[..]));
+}
+
+#[test]
+fn test_err() {
+    // Too many unused bits
+    match BitString::with_bytes(b"\xa0", 8) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Unused bits in an empty bit string
+    match from_bytes::<BitString>(b"\x03\x01\x01") {
+        Err(_) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Three unused bits with a stray set bit
+    match from_bytes::<BitString>(b"\x03\x02\x03\xa4") {
+        Err(_) => (),
+        _ => panic!("Invalid result"),
+    }
+    match BitString::with_bytes(b"\xa4", 3) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert_eq!(from_bytes::<BitString>(b"\x03\x02\x03\xa8").unwrap().as_bytes(), b"\xa8");
+}
@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+
+/// X.509 `BasicConstraints` without the `pathLenConstraint`
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct BasicConstraints {
+    #[serde(with = "serde_asn1_der::bool_default_false")]
+    ca: bool,
+}
+
+#[test]
+fn test() {
+    for (ca, der) in [(true, &b"\x30\x03\x01\x01\xff"[..]), (false, b"\x30\x00")] {
+        assert_eq!(to_vec(&BasicConstraints { ca }).unwrap(), der);
+        assert_eq!(from_bytes::<BasicConstraints>(der).unwrap(), BasicConstraints { ca });
+    }
+}
+
+#[test]
+fn test_err() {
+    // The default value must not be encoded
+    assert!(from_bytes::<BasicConstraints>(b"\x30\x03\x01\x01\x00").is_err());
+}
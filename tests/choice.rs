@@ -0,0 +1,82 @@
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error};
+
+/// A simplified `GeneralName`
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+enum GeneralName {
+    Rfc822Name(String),
+    DnsName(String),
+}
+
+/// A simplified choice of `TBSCertificate`-fields
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+enum TbsField {
+    Version(u8),
+    IssuerUniqueId(Vec<u8>),
+    Extensions(Vec<u8>),
+}
+
+/// A choice of differently typed alternatives
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+enum Mixed {
+    A(u32),
+    B(String),
+}
+
+#[test]
+fn test() {
+    let plain = GeneralName::Rfc822Name("a@b.de".to_string());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa0\x08\x0c\x06\x61\x40\x62\x2e\x64\x65");
+    assert_eq!(from_bytes::<GeneralName>(&encoded).unwrap(), plain);
+
+    let plain = GeneralName::DnsName("b.de".to_string());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa1\x06\x0c\x04\x62\x2e\x64\x65");
+    assert_eq!(from_bytes::<GeneralName>(&encoded).unwrap(), plain);
+
+    // Within a sequence
+    let plain = vec![GeneralName::DnsName("b.de".to_string()), GeneralName::Rfc822Name("a@b.de".to_string())];
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x12\xa1\x06\x0c\x04\x62\x2e\x64\x65\xa0\x08\x0c\x06\x61\x40\x62\x2e\x64\x65");
+    assert_eq!(from_bytes::<Vec<GeneralName>>(&encoded).unwrap(), plain);
+
+    // A wrapped SEQUENCE OF
+    let plain = TbsField::Extensions(vec![7]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa2\x05\x30\x03\x02\x01\x07");
+    assert_eq!(from_bytes::<TbsField>(&encoded).unwrap(), plain);
+}
+
+#[test]
+fn test_mixed() {
+    for (plain, der) in
+        [(Mixed::A(5), &b"\xa0\x03\x02\x01\x05"[..]), (Mixed::B("x".to_string()), b"\xa1\x03\x0c\x01\x78")]
+    {
+        assert_eq!(to_vec(&plain).unwrap(), der);
+        assert_eq!(from_bytes::<Mixed>(der).unwrap(), plain);
+    }
+
+    // The payload must match the type of the selected alternative
+    assert!(from_bytes::<Mixed>(b"\xa0\x03\x0c\x01\x78").is_err());
+}
+
+#[test]
+fn test_err() {
+    // An unknown variant
+    assert!(from_bytes::<GeneralName>(b"\xa2\x06\x0c\x04\x62\x2e\x64\x65").is_err());
+
+    // More than one wrapped object
+    match from_bytes::<GeneralName>(b"\xa1\x08\x0c\x04\x62\x2e\x64\x65\x05\x00") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // The wrapper is one byte shorter than the wrapped object
+    match from_bytes::<TbsField>(b"\xa2\x04\x30\x03\x02\x01") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "const-oid")]
+
+#[macro_use]
+extern crate serde_derive;
+use const_oid::ObjectIdentifier;
+use serde_asn1_der::{from_bytes, to_vec};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct AlgorithmIdentifier {
+    #[serde(with = "serde_asn1_der::const_oid")]
+    algorithm: ObjectIdentifier,
+    parameters: (),
+}
+
+#[test]
+fn test() {
+    // `rsaEncryption` with NULL parameters
+    let plain = AlgorithmIdentifier { algorithm: ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1"), parameters: () };
+    let der = b"\x30\x0d\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01\x05\x00";
+    assert_eq!(to_vec(&plain).unwrap(), der);
+    assert_eq!(from_bytes::<AlgorithmIdentifier>(der).unwrap(), plain);
+}
+
+#[test]
+fn test_err() {
+    // An OCTET STRING and a truncated subidentifier
+    for der in [&b"\x30\x07\x04\x03\x2a\x86\x48\x05\x00"[..], b"\x30\x07\x06\x03\x2a\x86\x86\x05\x00"] {
+        assert!(from_bytes::<AlgorithmIdentifier>(der).is_err());
+    }
+}
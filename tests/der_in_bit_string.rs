@@ -0,0 +1,40 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Outer {
+    number: u8,
+    #[serde(with = "serde_asn1_der::der_in_bit_string")]
+    inner: Vec<u32>,
+}
+
+#[test]
+fn test() {
+    let outer = Outer { number: 7, inner: vec![1, 128, 65536] };
+    let der = b"\x30\x14\x02\x01\x07\x03\x0f\x00\x30\x0c\x02\x01\x01\x02\x02\x00\x80\x02\x03\x01\x00\x00";
+    assert_eq!(to_vec(&outer).unwrap(), der);
+    assert_eq!(from_bytes::<Outer>(der).unwrap(), outer);
+
+    // A SEQUENCE OF that requires long-form lengths for the sequence and the BIT STRING
+    let outer = Outer { number: 7, inner: (0..100).collect() };
+    let encoded = to_vec(&outer).unwrap();
+    assert_eq!(&encoded[..11], b"\x30\x82\x01\x38\x02\x01\x07\x03\x82\x01\x31");
+    assert_eq!(&encoded[11..16], b"\x00\x30\x82\x01\x2c");
+    assert_eq!(encoded.len(), 4 + 0x138);
+    assert_eq!(from_bytes::<Outer>(&encoded).unwrap(), outer);
+}
+
+#[test]
+fn test_err() {
+    for der in [
+        // Unused bits
+        &b"\x30\x0b\x02\x01\x07\x03\x06\x01\x30\x03\x02\x01\x02"[..],
+        // An OCTET STRING instead of a BIT STRING
+        b"\x30\x0a\x02\x01\x07\x04\x05\x30\x03\x02\x01\x02",
+    ] {
+        assert!(from_bytes::<Outer>(der).is_err());
+    }
+}
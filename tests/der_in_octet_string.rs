@@ -0,0 +1,73 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Inner {
+    number: u8,
+    flag: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Outer {
+    critical: bool,
+    #[serde(with = "serde_asn1_der::der_in_octet_string")]
+    inner: Inner,
+}
+
+#[test]
+fn test() {
+    let outer = Outer { critical: true, inner: Inner { number: 7, flag: false } };
+    let der = b"\x30\x0d\x01\x01\xff\x04\x08\x30\x06\x02\x01\x07\x01\x01\x00";
+    assert_eq!(to_vec(&outer).unwrap(), der);
+    assert_eq!(from_bytes::<Outer>(der).unwrap(), outer);
+}
+
+#[test]
+fn test_err() {
+    for der in [
+        // Trailing data after the encapsulated object
+        &b"\x30\x0e\x01\x01\xff\x04\x09\x30\x06\x02\x01\x07\x01\x01\x00\x00"[..],
+        // A BIT STRING instead of an OCTET STRING
+        b"\x30\x0d\x01\x01\xff\x03\x08\x30\x06\x02\x01\x07\x01\x01\x00",
+        // An OCTET STRING that does not contain a sequence
+        b"\x30\x08\x01\x01\xff\x04\x03\x02\x01\x07",
+    ] {
+        assert!(from_bytes::<Outer>(der).is_err());
+    }
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct BorrowedOuter<'a> {
+    critical: bool,
+    #[serde(borrow, with = "serde_asn1_der::der_in_octet_string")]
+    inner: &'a str,
+}
+
+#[test]
+fn test_borrowed() {
+    // The encapsulated string is borrowed from the input
+    let der = b"\x30\x0a\x01\x01\xff\x04\x05\x0c\x03\x61\x62\x63";
+    let decoded: BorrowedOuter = from_bytes(der).unwrap();
+    assert_eq!(decoded, BorrowedOuter { critical: true, inner: "abc" });
+    assert_eq!(decoded.inner.as_ptr(), der[9..].as_ptr());
+}
+
+#[cfg(feature = "serde_with")]
+#[test]
+fn test_serde_as() {
+    use serde_asn1_der::DerInOctetString;
+
+    #[serde_with::serde_as]
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct SerdeAsOuter {
+        critical: bool,
+        #[serde_as(as = "DerInOctetString<Inner>")]
+        inner: Inner,
+    }
+
+    let outer = SerdeAsOuter { critical: true, inner: Inner { number: 7, flag: false } };
+    let der = b"\x30\x0d\x01\x01\xff\x04\x08\x30\x06\x02\x01\x07\x01\x01\x00";
+    assert_eq!(to_vec(&outer).unwrap(), der);
+    assert_eq!(from_bytes::<SerdeAsOuter>(der).unwrap(), outer);
+}
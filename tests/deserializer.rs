@@ -0,0 +1,470 @@
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{
+    Asn1DerError,
+    Asn1DerErrorVariant::{InvalidData, Unsupported},
+    VecBacking,
+};
+use serde::{
+    de::{self, Visitor},
+    Deserialize,
+};
+use serde_asn1_der::{
+    from_bytes, from_reader, from_reader_iter, Deserializer, EmptySeq,
+    SerdeAsn1DerError::{
+        Asn1DerError as Error, AtPath, ConstructedNotAllowed, LengthLimitExceeded, SerdeError, TrailingData,
+        UnexpectedEoc, UnexpectedTag,
+    },
+};
+use std::{
+    borrow::Cow,
+    fmt::{self, Formatter},
+};
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct Version1 {
+    number: u8,
+    option: Option<String>,
+}
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct Version1Int {
+    number: u8,
+    option: Option<u8>,
+}
+
+/// A number that replaces all errors with its own error
+#[derive(Debug, Eq, PartialEq)]
+struct Mapped(u8);
+impl<'de> Deserialize<'de> for Mapped {
+    fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Mapped).map_err(|_| de::Error::custom("Invalid number"))
+    }
+}
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct MappedOptional {
+    option: Option<Mapped>,
+    name: String,
+}
+
+/// A simplified certificate
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct Certificate {
+    tbs_certificate: TbsCertificate,
+}
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct TbsCertificate {
+    validity: Validity,
+    extensions: Vec<u8>,
+}
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct Validity {
+    not_before: u64,
+    not_after: u64,
+}
+
+/// A byte slice that can only be deserialized by borrowing from the input
+#[derive(Debug, Eq, PartialEq)]
+struct Borrowed<'a>(&'a [u8]);
+impl<'de> Deserialize<'de> for Borrowed<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct BorrowedVisitor;
+        impl<'de> Visitor<'de> for BorrowedVisitor {
+            type Value = Borrowed<'de>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                write!(formatter, "a borrowed byte slice")
+            }
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E> {
+                Ok(Borrowed(v))
+            }
+            fn visit_bytes<E: de::Error>(self, _v: &[u8]) -> Result<Self::Value, E> {
+                Err(E::custom("bytes are not borrowed"))
+            }
+        }
+        deserializer.deserialize_bytes(BorrowedVisitor)
+    }
+}
+
+#[test]
+fn test_borrowed_bytes() {
+    let der = b"\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let decoded: Borrowed = from_bytes(der).unwrap();
+    assert_eq!(decoded, Borrowed(b"Testolope"));
+    assert_eq!(decoded.0.as_ptr(), der[2..].as_ptr());
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct BorrowedStrs<'a> {
+    name: &'a str,
+    comment: &'a str,
+}
+
+#[test]
+fn test_borrowed_strs() {
+    let der = b"\x30\x0f\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x0c\x02\x4f\x4b";
+    let decoded: BorrowedStrs = from_bytes(der).unwrap();
+    assert_eq!(decoded, BorrowedStrs { name: "Testolope", comment: "OK" });
+    assert_eq!(decoded.name.as_ptr(), der[4..].as_ptr());
+    assert_eq!(decoded.comment.as_ptr(), der[15..].as_ptr());
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct BorrowedOctets<'a> {
+    number: u8,
+    #[serde(with = "serde_bytes")]
+    octets: &'a [u8],
+}
+
+#[test]
+fn test_borrowed_octets() {
+    let der = b"\x30\x09\x02\x01\x07\x04\x04\x01\x02\x03\x04";
+    let decoded: BorrowedOctets = from_bytes(der).unwrap();
+    assert_eq!(decoded, BorrowedOctets { number: 7, octets: b"\x01\x02\x03\x04" });
+    assert!(der.as_ptr_range().contains(&decoded.octets.as_ptr()));
+    assert_eq!(decoded.octets.as_ptr(), der[7..].as_ptr());
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct CowFields<'a> {
+    #[serde(borrow)]
+    name: Cow<'a, str>,
+    #[serde(borrow)]
+    octets: Cow<'a, [u8]>,
+}
+
+#[test]
+fn test_borrowed_cows() {
+    let der = b"\x30\x0a\x0c\x02\x4f\x4b\x04\x04\x01\x02\x03\x04";
+    let decoded: CowFields = from_bytes(der).unwrap();
+    assert_eq!(decoded, CowFields { name: Cow::Borrowed("OK"), octets: Cow::Borrowed(b"\x01\x02\x03\x04") });
+    match (&decoded.name, &decoded.octets) {
+        (Cow::Borrowed(name), Cow::Borrowed(octets)) => {
+            assert_eq!(name.as_ptr(), der[4..].as_ptr());
+            assert_eq!(octets.as_ptr(), der[8..].as_ptr());
+        }
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_content_borrowed() {
+    let der = b"\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let deserializer = Deserializer::from_bytes(der).unwrap();
+    let content = deserializer.content_borrowed();
+    assert_eq!(content, b"Testolope");
+    assert_eq!(content.as_ptr(), der[2..].as_ptr());
+}
+
+#[test]
+fn test_trailing_seq_elements() {
+    // A newer version with an unknown trailing field
+    let der = b"\x30\x0b\x02\x01\x07\x0c\x01\x61\x0c\x03\x6e\x65\x77";
+    let decoded: Version1 = from_bytes(der).unwrap();
+    assert_eq!(decoded, Version1 { number: 7, option: Some("a".to_string()) });
+
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_allow_trailing_seq_elements(false);
+    match Version1::deserialize(&mut deserializer) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // An absent optional field followed by an unknown field, which is only read as the optional field if the tag
+    //  matches
+    let der = b"\x30\x08\x02\x01\x07\x0c\x03\x6e\x65\x77";
+    let decoded: Version1Int = from_bytes(der).unwrap();
+    assert_eq!(decoded, Version1Int { number: 7, option: None });
+    let decoded: Version1 = from_bytes(der).unwrap();
+    assert_eq!(decoded, Version1 { number: 7, option: Some("new".to_string()) });
+
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_allow_trailing_seq_elements(false);
+    match Version1Int::deserialize(&mut deserializer) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Absence only depends on the tag, not on the errors of the type
+    let der = b"\x30\x0b\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let decoded: MappedOptional = from_bytes(der).unwrap();
+    assert_eq!(decoded, MappedOptional { option: None, name: "Testolope".to_string() });
+    let der = b"\x30\x0e\x02\x01\x07\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let decoded: MappedOptional = from_bytes(der).unwrap();
+    assert_eq!(decoded, MappedOptional { option: Some(Mapped(7)), name: "Testolope".to_string() });
+    match from_bytes::<MappedOptional>(b"\x30\x06\x02\x01\x07\x02\x01\x08") {
+        Err(UnexpectedTag { expected: 0x0c, found: 0x02 }) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_accept_any_string_tag() {
+    // A PrintableString
+    let der = b"\x13\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    match from_bytes::<String>(der) {
+        Err(UnexpectedTag { expected: 0x0c, found: 0x13 }) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_accept_any_string_tag(true);
+    let decoded = String::deserialize(&mut deserializer).unwrap();
+    assert_eq!(decoded, "Testolope");
+
+    // A PrintableString with an invalid character
+    let mut deserializer = Deserializer::from_bytes(b"\x13\x01\x2a").unwrap();
+    deserializer.set_accept_any_string_tag(true);
+    match String::deserialize(&mut deserializer) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_track_path() {
+    // Valid objects are not affected
+    let der = b"\x30\x0f\x30\x0d\x30\x06\x02\x01\x01\x02\x01\x02\x30\x03\x02\x01\x07";
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_track_path(true);
+    let validity = Validity { not_before: 1, not_after: 2 };
+    let tbs_certificate = TbsCertificate { validity, extensions: vec![7] };
+    assert_eq!(Certificate::deserialize(&mut deserializer).unwrap(), Certificate { tbs_certificate });
+
+    // `not_after` is an OCTET STRING instead of an INTEGER
+    let der = b"\x30\x0f\x30\x0d\x30\x06\x02\x01\x01\x04\x01\x02\x30\x03\x02\x01\x07";
+    match from_bytes::<Certificate>(der) {
+        Err(UnexpectedTag { expected: 0x02, found: 0x04 }) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_track_path(true);
+    match Certificate::deserialize(&mut deserializer) {
+        Err(e @ AtPath(..)) => {
+            assert!(e.to_string().ends_with("(at `tbs_certificate.validity.not_after`)"));
+            match e {
+                AtPath(path, e) => {
+                    assert_eq!(path, "tbs_certificate.validity.not_after");
+                    assert!(matches!(*e, UnexpectedTag { expected: 0x02, found: 0x04 }));
+                }
+                _ => unreachable!(),
+            }
+        }
+        _ => panic!("Invalid result"),
+    }
+
+    // The second extension is a NULL instead of an INTEGER
+    let der = b"\x30\x13\x30\x11\x30\x06\x02\x01\x01\x02\x01\x02\x30\x07\x02\x01\x07\x05\x00\x02\x00";
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_track_path(true);
+    match Certificate::deserialize(&mut deserializer) {
+        Err(AtPath(path, _)) => assert_eq!(path, "tbs_certificate.extensions.1"),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_max_length() {
+    // A declared length of 2 GiB is rejected before the value is read
+    let mut backing = Vec::new();
+    match from_reader::<&[u8]>(&b"\x04\x84\x7f\xff\xff\xff\x00"[..], VecBacking(&mut backing)) {
+        Err(LengthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert!(backing.is_empty());
+
+    // The same applies to the elements of a streamed sequence
+    let mut elements = from_reader_iter::<Vec<u8>, _>(&b"\x30\x06\x04\x84\x7f\xff\xff\xff"[..]).unwrap();
+    assert!(matches!(elements.next(), Some(Err(LengthLimitExceeded))));
+
+    // A custom limit
+    let der = b"\x04\x04\x01\x02\x03\x04";
+    let mut backing = Vec::new();
+    let mut deserializer = Deserializer::from_source_with_max_length(der.iter(), VecBacking(&mut backing), 4).unwrap();
+    assert_eq!(<&[u8]>::deserialize(&mut deserializer).unwrap(), b"\x01\x02\x03\x04");
+    match Deserializer::from_source_with_max_length(der.iter(), VecBacking(&mut Vec::new()), 3) {
+        Err(LengthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_bool_from_integer() {
+    match from_bytes::<bool>(b"\x02\x01\x01") {
+        Err(UnexpectedTag { expected: 0x01, found: 0x02 }) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    for (der, bool) in [(&b"\x02\x01\x01"[..], true), (b"\x02\x01\x00", false), (b"\x01\x01\xff", true)] {
+        let mut deserializer = Deserializer::from_bytes(der).unwrap();
+        deserializer.set_bool_from_integer(true);
+        assert_eq!(bool::deserialize(&mut deserializer).unwrap(), bool);
+    }
+
+    // Other values are still rejected
+    let mut deserializer = Deserializer::from_bytes(b"\x02\x01\x02").unwrap();
+    deserializer.set_bool_from_integer(true);
+    match bool::deserialize(&mut deserializer) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_lenient_bool() {
+    match from_bytes::<bool>(b"\x01\x01\x01") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    for (der, bool) in [(&b"\x01\x01\x01"[..], true), (b"\x01\x01\x80", true), (b"\x01\x01\x00", false)] {
+        let mut deserializer = Deserializer::from_bytes(der).unwrap();
+        deserializer.set_lenient_bool(true);
+        assert_eq!(bool::deserialize(&mut deserializer).unwrap(), bool);
+    }
+
+    // The value must still be a single byte
+    let mut deserializer = Deserializer::from_bytes(b"\x01\x02\x01\x01").unwrap();
+    deserializer.set_lenient_bool(true);
+    match bool::deserialize(&mut deserializer) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_trailing_data() {
+    let der = [0x02, 0x01, 0x05, 0xff];
+    match from_bytes::<u8>(&der) {
+        Err(TrailingData) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // The first object can still be read explicitly
+    let mut deserializer = Deserializer::from_bytes(&der).unwrap();
+    assert_eq!(deserializer.trailing_len(), 1);
+    assert_eq!(u8::deserialize(&mut deserializer).unwrap(), 5);
+    assert_eq!(Deserializer::from_bytes(&der[..3]).unwrap().trailing_len(), 0);
+}
+
+#[test]
+fn test_err_eoc() {
+    match from_bytes::<()>(b"\x00\x00") {
+        Err(UnexpectedEoc) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<(u8,)>(b"\x30\x05\x02\x01\x07\x00\x00") {
+        Err(UnexpectedEoc) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_integer() {
+    assert_eq!(from_bytes::<u8>(b"\x02\x02\x00\xff").unwrap(), 255);
+    assert_eq!(from_bytes::<u128>(b"\x02\x01\x00").unwrap(), 0);
+
+    // Non-minimal encodings
+    for der in [&b"\x02\x00"[..], b"\x02\x02\x00\x7f", b"\x02\x02\xff\x80"] {
+        match from_bytes::<u64>(der) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+    // Negative and too large numbers
+    for der in [&b"\x02\x01\x80"[..], b"\x02\x02\x01\x00"] {
+        match from_bytes::<u8>(der) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
+
+#[test]
+fn test_err_i128_overflow() {
+    #[derive(Deserialize, Debug)]
+    struct Wide {
+        _value: i128,
+    }
+
+    // A 20-byte INTEGER does not fit into `i128` and must not be truncated
+    let der = [&b"\x30\x16\x02\x14\x01"[..], &[0x00; 19]].concat();
+    match from_bytes::<Wide>(&der) {
+        Err(Error(Asn1DerError { error: Unsupported(desc), .. })) => assert!(desc.contains("BigInteger")),
+        _ => panic!("Invalid result"),
+    }
+
+    // 16 bytes are the limit
+    let der = [&b"\x02\x10\x80"[..], &[0x00; 15]].concat();
+    assert_eq!(from_bytes::<i128>(&der).unwrap(), i128::MIN);
+    let der = [&b"\x02\x11\x00\x80"[..], &[0x00; 15]].concat();
+    match from_bytes::<i128>(&der) {
+        Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_err_kind() {
+    #[derive(Deserialize, Debug)]
+    struct Flag {
+        _flag: bool,
+    }
+
+    // An invalid boolean is an encoding error and not a custom serde error
+    let err = from_bytes::<bool>(b"\x01\x01\x01").unwrap_err();
+    assert!(matches!(err, Error(Asn1DerError { error: InvalidData(_), .. })));
+    assert!(matches!(err.asn1_der_variant(), Some(InvalidData(_))));
+
+    // The kind is also available through the path of the failing element
+    let mut deserializer = Deserializer::from_bytes(b"\x30\x03\x01\x01\x01").unwrap();
+    deserializer.set_track_path(true);
+    let err = Flag::deserialize(&mut deserializer).unwrap_err();
+    assert!(matches!(err, AtPath(..)));
+    assert!(matches!(err.asn1_der_variant(), Some(InvalidData(_))));
+
+    // Custom errors have no kind
+    let err = from_bytes::<EmptySeq>(b"\x30\x03\x02\x01\x07").unwrap_err();
+    assert!(matches!(err, SerdeError(_)));
+    assert!(err.asn1_der_variant().is_none());
+}
+
+#[test]
+fn test_err_constructed() {
+    match from_bytes::<u8>(b"\x22\x03\x02\x01\x07") {
+        Err(ConstructedNotAllowed(0x22)) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<String>(b"\x2c\x03\x0c\x01\x61") {
+        Err(ConstructedNotAllowed(0x2c)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_err_multi_byte_tag() {
+    // `[31] IMPLICIT INTEGER` and `[200] IMPLICIT INTEGER` in the high-tag-number form, top-level and nested
+    for der in [
+        &b"\x9f\x1f\x01\x07"[..],
+        b"\x9f\x81\x48\x01\x07",
+        b"\x30\x04\x9f\x1f\x01\x07",
+        b"\x30\x05\x9f\x81\x48\x01\x07",
+    ] {
+        match from_bytes::<(u8,)>(der) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            r => panic!("Invalid result: {:?}", r),
+        }
+        let mut backing = Vec::new();
+        match from_reader::<(u8,)>(der, VecBacking(&mut backing)) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            r => panic!("Invalid result: {:?}", r),
+        }
+        match serde_asn1_der::validate_der(der) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            r => panic!("Invalid result: {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use serde_asn1_der::der_diff;
+
+/// Encodes a DER object with a short length
+fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut object = vec![tag, value.len() as u8];
+    object.extend_from_slice(value);
+    object
+}
+
+/// Creates a stripped-down certificate with the given serial number
+fn certificate(serial: u8) -> Vec<u8> {
+    let algorithm = tlv(0x30, &tlv(0x06, b"\x2a\x86\x48\xce\x3d\x04\x03\x02"));
+    let name = tlv(0x30, &tlv(0x31, &tlv(0x30, &[tlv(0x06, b"\x55\x04\x03"), tlv(0x0c, b"Test")].concat())));
+    let tbs_certificate = tlv(
+        0x30,
+        &[tlv(0xa0, &tlv(0x02, b"\x02")), tlv(0x02, &[serial]), algorithm.clone(), name.clone(), name].concat(),
+    );
+    tlv(0x30, &[tbs_certificate, algorithm, tlv(0x03, b"\x00\x01\x02\x03")].concat())
+}
+
+#[test]
+fn test() {
+    assert_eq!(der_diff(&certificate(4), &certificate(4)), None);
+
+    let diff = der_diff(&certificate(4), &certificate(7)).unwrap();
+    assert_eq!(diff.path, [0, 1]);
+    assert_eq!((diff.tag_a(), diff.tag_b()), (Some(0x02), Some(0x02)));
+    assert_eq!((diff.a.as_deref(), diff.b.as_deref()), (Some(&b"\x02\x01\x04"[..]), Some(&b"\x02\x01\x07"[..])));
+    assert_eq!(diff.to_string(), "/0/1: 02 01 04 != 02 01 07");
+}
+
+#[test]
+fn test_missing() {
+    let diff = der_diff(b"\x30\x05\x02\x01\x07\x05\x00", b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(diff.path, [1]);
+    assert_eq!((diff.a.as_deref(), diff.b.as_deref()), (Some(&b"\x05\x00"[..]), None));
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "any")]
+
+use serde_asn1_der::asn1_dump;
+
+/// Encodes a DER object with a short length
+fn tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut object = vec![tag, value.len() as u8];
+    object.extend_from_slice(value);
+    object
+}
+
+/// Creates an OCSP request for a single certificate with a nonce extension
+fn ocsp_request() -> Vec<u8> {
+    let algorithm = tlv(0x30, &[tlv(0x06, b"\x2b\x0e\x03\x02\x1a"), tlv(0x05, b"")].concat());
+    let cert_id =
+        tlv(0x30, &[algorithm, tlv(0x04, &[0x11; 20]), tlv(0x04, &[0x22; 20]), tlv(0x02, b"\x01\x02")].concat());
+    let nonce =
+        tlv(0x30, &[tlv(0x06, b"\x2b\x06\x01\x05\x05\x07\x30\x01\x02"), tlv(0x04, &tlv(0x04, b"\xca\xfe"))].concat());
+    let tbs_request = tlv(0x30, &[tlv(0x30, &tlv(0x30, &cert_id)), tlv(0xa2, &tlv(0x30, &nonce))].concat());
+    tlv(0x30, &tbs_request)
+}
+
+#[test]
+fn test() {
+    let dump = asn1_dump(&ocsp_request()).unwrap();
+    let lines: Vec<&str> = dump.lines().collect();
+    assert_eq!(lines.len(), 16);
+    assert_eq!(lines[0], "    0:d=0  hl=2 l=  90 cons: SEQUENCE");
+    assert_eq!(lines[6], "   12:d=6  hl=2 l=   5 prim: OBJECT            :1.3.14.3.2.26");
+    assert_eq!(lines[7], "   19:d=6  hl=2 l=   0 prim: NULL");
+    assert_eq!(
+        lines[8],
+        "   21:d=5  hl=2 l=  20 prim: OCTET STRING      :[HEX DUMP]:1111111111111111111111111111111111111111"
+    );
+    assert_eq!(lines[10], "   65:d=5  hl=2 l=   2 prim: INTEGER           :0102");
+    assert_eq!(lines[11], "   69:d=2  hl=2 l=  21 cons: cont [ 2 ]");
+    assert_eq!(lines[15], "   86:d=5  hl=2 l=   4 prim: OCTET STRING      :[HEX DUMP]:0402CAFE");
+}
+
+#[test]
+fn test_invalid() {
+    asn1_dump(b"\x30\x03\x02\x01").unwrap_err();
+    asn1_dump(b"\x30\x03\x02\x05\x00").unwrap_err();
+}
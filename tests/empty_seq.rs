@@ -0,0 +1,35 @@
+use serde_asn1_der::{
+    from_bytes, to_vec, EmptySeq,
+    SerdeAsn1DerError::{SerdeError, UnexpectedTag},
+};
+
+#[test]
+fn test() {
+    assert_eq!(to_vec(&EmptySeq).unwrap(), b"\x30\x00");
+    assert_eq!(from_bytes::<EmptySeq>(b"\x30\x00").unwrap(), EmptySeq);
+
+    // `()` stays NULL
+    assert_eq!(to_vec(&()).unwrap(), b"\x05\x00");
+    from_bytes::<()>(b"\x05\x00").unwrap();
+
+    // Within a sequence
+    let encoded = to_vec(&(7u8, EmptySeq, ())).unwrap();
+    assert_eq!(encoded, b"\x30\x07\x02\x01\x07\x30\x00\x05\x00");
+    assert_eq!(from_bytes::<(u8, EmptySeq, ())>(&encoded).unwrap(), (7, EmptySeq, ()));
+}
+
+#[test]
+fn test_err() {
+    // NULL is not an empty sequence and vice versa
+    match from_bytes::<EmptySeq>(b"\x05\x00") {
+        Err(UnexpectedTag { expected: 0x30, found: 0x05 }) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert!(from_bytes::<()>(b"\x30\x00").is_err());
+
+    // A non-empty sequence
+    match from_bytes::<EmptySeq>(b"\x30\x03\x02\x01\x07") {
+        Err(SerdeError(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::Unsupported};
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+enum Reason {
+    Unspecified = 0,
+    KeyCompromise = 1,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+enum Payload {
+    Bytes(u8),
+}
+
+#[test]
+fn test() {
+    assert_eq!(to_vec(&Reason::KeyCompromise).unwrap(), b"\x0a\x01\x01");
+    assert_eq!(from_bytes::<Reason>(b"\x0a\x01\x01").unwrap(), Reason::KeyCompromise);
+    assert_eq!(to_vec(&Reason::Unspecified).unwrap(), b"\x0a\x01\x00");
+    assert_eq!(from_bytes::<Reason>(b"\x0a\x01\x00").unwrap(), Reason::Unspecified);
+}
+
+#[test]
+fn test_err() {
+    // An unknown variant
+    match from_bytes::<Reason>(b"\x0a\x01\x02") {
+        Err(serde_asn1_der::SerdeAsn1DerError::SerdeError(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+    // A variant with payload
+    match from_bytes::<Payload>(b"\x0a\x01\x00") {
+        Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,31 @@
+use serde_asn1_der::SerdeAsn1DerError;
+use std::error::Error;
+
+#[test]
+fn test() {
+    for (error, message) in [
+        (SerdeAsn1DerError::UnexpectedEoc, "Unexpected end-of-contents marker in DER data"),
+        (SerdeAsn1DerError::DepthLimitExceeded, "The nesting depth limit has been exceeded"),
+        (SerdeAsn1DerError::LengthLimitExceeded, "The object length limit has been exceeded"),
+        (SerdeAsn1DerError::TrailingData, "The DER object is followed by trailing data"),
+        (SerdeAsn1DerError::ConstructedNotAllowed(0x24), "The primitive type has a constructed encoding (tag 0x24)"),
+        (
+            SerdeAsn1DerError::UnexpectedTag { expected: 0x01, found: 0x02 },
+            "The DER object has an unexpected tag (expected 0x01, found 0x02)",
+        ),
+    ] {
+        assert_eq!(error.to_string(), message);
+        assert!(error.source().is_none());
+    }
+}
+
+#[test]
+fn test_non_exhaustive() {
+    // Downstream `match`es must have a wildcard arm since the enum is `#[non_exhaustive]`
+    let kind = match SerdeAsn1DerError::TrailingData {
+        SerdeAsn1DerError::Asn1DerError(_) | SerdeAsn1DerError::SerdeError(_) => "decoding",
+        SerdeAsn1DerError::TrailingData => "trailing",
+        _ => "other",
+    };
+    assert_eq!(kind, "trailing");
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{
+    Asn1DerError,
+    Asn1DerErrorVariant::{InvalidData, Unsupported},
+};
+use serde_asn1_der::{
+    from_bytes, to_vec, ClassTagged, Explicit,
+    SerdeAsn1DerError::{Asn1DerError as Error, UnexpectedTag},
+};
+
+/// A simplified `TBSCertificate` with an explicitly tagged version and explicitly tagged extensions
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestStruct {
+    version: Explicit<0, u8>,
+    serial: u64,
+    extensions: Option<Explicit<3, Vec<u8>>>,
+}
+
+#[test]
+fn test() {
+    // `[0] EXPLICIT INTEGER`
+    let plain: Explicit<0, u8> = Explicit(2);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa0\x03\x02\x01\x02");
+    assert_eq!(from_bytes::<Explicit<0, u8>>(&encoded).unwrap(), plain);
+
+    // Constructed values are wrapped too
+    let plain: Explicit<30, Vec<u8>> = Explicit(vec![1, 2]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xbe\x08\x30\x06\x02\x01\x01\x02\x01\x02");
+    assert_eq!(from_bytes::<Explicit<30, Vec<u8>>>(&encoded).unwrap(), plain);
+
+    // As optional element
+    for (plain, der) in [
+        (
+            TestStruct { version: Explicit(2), serial: 7, extensions: Some(Explicit(vec![4])) },
+            &b"\x30\x0f\xa0\x03\x02\x01\x02\x02\x01\x07\xa3\x05\x30\x03\x02\x01\x04"[..],
+        ),
+        (TestStruct { version: Explicit(2), serial: 7, extensions: None }, b"\x30\x08\xa0\x03\x02\x01\x02\x02\x01\x07"),
+    ] {
+        assert_eq!(to_vec(&plain).unwrap(), der);
+        assert_eq!(from_bytes::<TestStruct>(der).unwrap(), plain);
+    }
+}
+
+#[test]
+fn test_absent_version() {
+    // An absent `[0] EXPLICIT` version in front of the serial
+    #[derive(Deserialize, Debug, Eq, PartialEq)]
+    struct TbsCertificate {
+        version: Option<Explicit<0, u8>>,
+        serial: u64,
+    }
+    let decoded: TbsCertificate = from_bytes(b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(decoded, TbsCertificate { version: None, serial: 7 });
+    let decoded: TbsCertificate = from_bytes(b"\x30\x08\xa0\x03\x02\x01\x02\x02\x01\x07").unwrap();
+    assert_eq!(decoded, TbsCertificate { version: Some(Explicit(2)), serial: 7 });
+}
+
+#[test]
+fn test_err() {
+    // The wrong tag number, the universal tag and the implicit form
+    for der in [&b"\xa1\x03\x02\x01\x02"[..], b"\x30\x03\x02\x01\x02", b"\x80\x01\x02"] {
+        match from_bytes::<Explicit<0, u8>>(der) {
+            Err(UnexpectedTag { expected: 0xa0, found }) if found == der[0] => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    // More than one wrapped object
+    match from_bytes::<Explicit<0, u8>>(b"\xa0\x06\x02\x01\x02\x02\x01\x03") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // `None` would be written as empty tagged object, which cannot be read back
+    match to_vec(&Explicit::<0, Option<u8>>(None)) {
+        Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert_eq!(to_vec(&Explicit::<0, Option<u8>>(Some(2))).unwrap(), b"\xa0\x03\x02\x01\x02");
+}
+
+#[test]
+fn test_class_tagged() {
+    // `[APPLICATION 5] EXPLICIT INTEGER` and `[PRIVATE 1] EXPLICIT SEQUENCE OF INTEGER`
+    let plain: ClassTagged<0b01, 5, u8> = ClassTagged(7);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x65\x03\x02\x01\x07");
+    assert_eq!(from_bytes::<ClassTagged<0b01, 5, u8>>(&encoded).unwrap(), plain);
+
+    let plain: ClassTagged<0b11, 1, Vec<u8>> = ClassTagged(vec![7]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xe1\x05\x30\x03\x02\x01\x07");
+    assert_eq!(from_bytes::<ClassTagged<0b11, 1, Vec<u8>>>(&encoded).unwrap(), plain);
+
+    // The context-specific class is equivalent to `Explicit`
+    assert_eq!(to_vec(&ClassTagged::<0b10, 2, u8>(7)).unwrap(), to_vec(&Explicit::<2, u8>(7)).unwrap());
+
+    // The class must match
+    for der in [&b"\xa5\x03\x02\x01\x07"[..], b"\xe5\x03\x02\x01\x07"] {
+        match from_bytes::<ClassTagged<0b01, 5, u8>>(der) {
+            Err(UnexpectedTag { expected: 0x65, found }) if found == der[0] => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
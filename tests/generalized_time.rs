@@ -0,0 +1,41 @@
+#![cfg(feature = "more_types")]
+
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{from_bytes, to_vec, GeneralizedTime, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[test]
+fn test() {
+    for (timestamp, der) in [
+        // 2050-01-01T00:00:00Z, which cannot be expressed as UTCTime
+        (2_524_608_000, &b"\x18\x0f20500101000000Z"[..]),
+        (0, b"\x18\x0f19700101000000Z"),
+        (951_825_845, b"\x18\x0f20000229120405Z"),
+        (-1, b"\x18\x0f19691231235959Z"),
+        (253_402_300_799, b"\x18\x0f99991231235959Z"),
+    ] {
+        assert_eq!(to_vec(&GeneralizedTime(timestamp)).unwrap(), der);
+        assert_eq!(from_bytes::<GeneralizedTime>(der).unwrap(), GeneralizedTime(timestamp));
+    }
+}
+
+#[test]
+fn test_err() {
+    // Missing `Z`, wrong length, fractional seconds, invalid date and invalid time
+    for bytes in [
+        &b"20500101000000+"[..],
+        b"205001010000Z",
+        b"20500101000000.5Z",
+        b"20500229000000Z",
+        b"20501301000000Z",
+        b"20500101240000Z",
+    ] {
+        match GeneralizedTime::from_bytes(bytes) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+
+    // Invalid tag and out-of-range timestamp
+    assert!(from_bytes::<GeneralizedTime>(b"\x17\x0d500101000000Z").is_err());
+    assert!(to_vec(&GeneralizedTime(253_402_300_800)).is_err());
+}
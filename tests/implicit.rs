@@ -0,0 +1,90 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{
+    from_bytes, to_vec, BitString, Explicit, Implicit, PrintableString,
+    SerdeAsn1DerError::{ConstructedNotAllowed, UnexpectedTag},
+    SetOf,
+};
+
+/// A simplified `TBSCertList`-tail with an implicitly tagged SEQUENCE OF
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestStruct {
+    number: u8,
+    serials: Option<Implicit<0, Vec<u64>>>,
+}
+
+#[test]
+fn test() {
+    // `[0] IMPLICIT SEQUENCE OF INTEGER`
+    let plain: Implicit<0, Vec<u8>> = Implicit(vec![1, 2]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa0\x06\x02\x01\x01\x02\x01\x02");
+    assert_eq!(from_bytes::<Implicit<0, Vec<u8>>>(&encoded).unwrap(), plain);
+
+    // Primitive values keep their primitive form
+    let plain: Implicit<1, u8> = Implicit(7);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x81\x01\x07");
+    assert_eq!(from_bytes::<Implicit<1, u8>>(&encoded).unwrap(), plain);
+
+    let plain: Implicit<30, BitString> = Implicit(BitString::with_bits(&[true]));
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x9e\x02\x07\x80");
+    assert_eq!(from_bytes::<Implicit<30, BitString>>(&encoded).unwrap(), plain);
+
+    // Strings and SETs
+    let plain: Implicit<2, String> = Implicit("Testolope".to_string());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x82\x09Testolope");
+    assert_eq!(from_bytes::<Implicit<2, String>>(&encoded).unwrap(), plain);
+
+    let plain: Implicit<3, PrintableString> = Implicit(PrintableString("Testolope".to_string()));
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x83\x09Testolope");
+    assert_eq!(from_bytes::<Implicit<3, PrintableString>>(&encoded).unwrap(), plain);
+
+    let plain: Implicit<4, SetOf<u8>> = Implicit(SetOf(vec![2, 1]));
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\xa4\x06\x02\x01\x01\x02\x01\x02");
+    assert_eq!(from_bytes::<Implicit<4, SetOf<u8>>>(&encoded).unwrap(), Implicit(SetOf(vec![1, 2])));
+
+    // As optional element
+    for (plain, der) in [
+        (TestStruct { number: 7, serials: Some(Implicit(vec![4])) }, &b"\x30\x08\x02\x01\x07\xa0\x03\x02\x01\x04"[..]),
+        (TestStruct { number: 7, serials: None }, b"\x30\x03\x02\x01\x07"),
+    ] {
+        assert_eq!(to_vec(&plain).unwrap(), der);
+        assert_eq!(from_bytes::<TestStruct>(der).unwrap(), plain);
+    }
+}
+
+#[test]
+fn test_explicit() {
+    // The implicit form replaces the tag of the INTEGER, while the explicit form wraps it
+    assert_eq!(to_vec(&Implicit::<0, u64>(7)).unwrap(), b"\x80\x01\x07");
+    assert_eq!(to_vec(&Explicit::<0, u64>(7)).unwrap(), b"\xa0\x03\x02\x01\x07");
+
+    // Constructed values keep the constructed bit in the implicit form
+    assert_eq!(to_vec(&Implicit::<0, Vec<u64>>(vec![7])).unwrap(), b"\xa0\x03\x02\x01\x07");
+    assert_eq!(to_vec(&Explicit::<0, Vec<u64>>(vec![7])).unwrap(), b"\xa0\x05\x30\x03\x02\x01\x07");
+
+    // The forms are not interchangeable
+    match from_bytes::<Implicit<0, u64>>(b"\xa0\x03\x02\x01\x07") {
+        Err(ConstructedNotAllowed(0xa0)) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert!(from_bytes::<Explicit<0, u64>>(b"\x80\x01\x07").is_err());
+}
+
+#[test]
+fn test_err() {
+    // The wrong tag number and the universal tag
+    for der in [&b"\xa1\x06\x02\x01\x01\x02\x01\x02"[..], b"\x30\x06\x02\x01\x01\x02\x01\x02"] {
+        match from_bytes::<Implicit<0, Vec<u8>>>(der) {
+            Err(UnexpectedTag { expected: 0xa0, found }) if found == der[0] => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::SerdeError};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Attributes {
+    #[serde(with = "serde_asn1_der::indexed")]
+    attributes: HashMap<u8, String>,
+}
+
+#[test]
+fn test() {
+    let der = b"\x30\x12\x30\x10\x30\x06\x02\x01\x07\x0c\x01\x61\x30\x06\x02\x01\x04\x0c\x01\x62";
+    let decoded: Attributes = from_bytes(der).unwrap();
+    assert_eq!(decoded.attributes.len(), 2);
+    assert_eq!((decoded.attributes[&7].as_str(), decoded.attributes[&4].as_str()), ("a", "b"));
+
+    // A single entry is encoded deterministically
+    let plain = Attributes { attributes: vec![(7, "a".to_string())].into_iter().collect() };
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x0a\x30\x08\x30\x06\x02\x01\x07\x0c\x01\x61");
+    assert_eq!(from_bytes::<Attributes>(&encoded).unwrap(), plain);
+}
+
+#[test]
+fn test_err() {
+    // A duplicate key
+    let der = b"\x30\x12\x30\x10\x30\x06\x02\x01\x07\x0c\x01\x61\x30\x06\x02\x01\x07\x0c\x01\x62";
+    match from_bytes::<Attributes>(der) {
+        Err(SerdeError(desc)) if desc.contains("duplicate") => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+#[cfg(all(feature = "any", feature = "more_types"))]
+fn test_attributes() {
+    use serde_asn1_der::{AnyObject, ObjectIdentifier};
+
+    /// A simplified PKCS#10-attribute list
+    #[derive(Deserialize)]
+    struct Attributes {
+        #[serde(with = "serde_asn1_der::indexed")]
+        attributes: HashMap<ObjectIdentifier, Box<dyn AnyObject>>,
+    }
+
+    // `challengePassword` (1.2.840.113549.1.9.7) and `unstructuredName` (1.2.840.113549.1.9.2)
+    let der = [
+        &b"\x30\x2c\x30\x2a"[..],
+        b"\x30\x13\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x09\x07\x0c\x06\x73\x65\x63\x72\x65\x74",
+        b"\x30\x13\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x09\x02\x0c\x06\x64\x65\x76\x69\x63\x65",
+    ]
+    .concat();
+    let decoded: Attributes = from_bytes(&der).unwrap();
+
+    let challenge_password = ObjectIdentifier::from_arcs(&[1, 2, 840, 113549, 1, 9, 7]).unwrap();
+    let unstructured_name = ObjectIdentifier::from_arcs(&[1, 2, 840, 113549, 1, 9, 2]).unwrap();
+    let value = |oid| decoded.attributes[oid].as_ref().as_any().downcast_ref::<String>().cloned();
+    assert_eq!(value(&challenge_password).as_deref(), Some("secret"));
+    assert_eq!(value(&unstructured_name).as_deref(), Some("device"));
+}
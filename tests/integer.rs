@@ -0,0 +1,41 @@
+use asn1_der::{
+    Asn1DerError,
+    Asn1DerErrorVariant::{InvalidData, Unsupported},
+};
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[test]
+fn test_signed() {
+    assert_eq!(to_vec(&-1i8).unwrap(), b"\x02\x01\xff");
+    assert_eq!(to_vec(&0i16).unwrap(), b"\x02\x01\x00");
+    assert_eq!(to_vec(&127i32).unwrap(), b"\x02\x01\x7f");
+    assert_eq!(to_vec(&128i32).unwrap(), b"\x02\x02\x00\x80");
+    assert_eq!(to_vec(&-128i64).unwrap(), b"\x02\x01\x80");
+    assert_eq!(to_vec(&-129i64).unwrap(), b"\x02\x02\xff\x7f");
+    assert_eq!(to_vec(&i128::MIN).unwrap(), [&b"\x02\x10\x80"[..], &[0; 15]].concat());
+
+    assert_eq!(from_bytes::<i8>(b"\x02\x01\xff").unwrap(), -1);
+    assert_eq!(from_bytes::<i16>(b"\x02\x01\x00").unwrap(), 0);
+    assert_eq!(from_bytes::<i32>(b"\x02\x02\x00\x80").unwrap(), 128);
+    assert_eq!(from_bytes::<i64>(b"\x02\x01\x80").unwrap(), -128);
+    assert_eq!(from_bytes::<i64>(b"\x02\x02\xff\x7f").unwrap(), -129);
+    assert_eq!(from_bytes::<i128>(&to_vec(&i128::MIN).unwrap()).unwrap(), i128::MIN);
+}
+
+#[test]
+fn test_signed_err() {
+    // Non-minimal encodings
+    for der in [&b"\x02\x02\x00\x01"[..], b"\x02\x02\xff\xff"] {
+        match from_bytes::<i32>(der) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+    // Out of range
+    for der in [&b"\x02\x02\x00\x80"[..], b"\x02\x02\xff\x7f"] {
+        match from_bytes::<i8>(der) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
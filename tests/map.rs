@@ -0,0 +1,60 @@
+#[test]
+#[cfg(not(feature = "map"))]
+fn test_err_feature() {
+    use asn1_der::{Asn1DerError, Asn1DerErrorVariant::Unsupported};
+    use serde_asn1_der::{from_bytes, SerdeAsn1DerError::Asn1DerError as Error};
+    use std::collections::HashMap;
+
+    // A sequence with a single key-value pair
+    let der = b"\x30\x08\x30\x06\x02\x01\x07\x02\x01\x04";
+    match from_bytes::<HashMap<u8, u8>>(der) {
+        Err(Error(Asn1DerError { error: Unsupported(desc), .. })) if desc.contains("`map`") => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+#[cfg(feature = "map")]
+fn test() {
+    use serde_asn1_der::{from_bytes, to_slice, to_vec};
+    use std::collections::BTreeMap;
+
+    // The entries are written in the iteration order of the map
+    let plain: BTreeMap<u32, String> = vec![(2, "b".to_string()), (1, "a".to_string())].into_iter().collect();
+    let der = b"\x30\x10\x30\x06\x02\x01\x01\x0c\x01\x61\x30\x06\x02\x01\x02\x0c\x01\x62";
+
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, der.as_ref());
+
+    let mut buf = [0; 32];
+    let len = to_slice(&plain, &mut buf).unwrap();
+    assert_eq!(&buf[..len], der.as_ref());
+
+    let decoded: BTreeMap<u32, String> = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+
+    let empty = to_vec(&BTreeMap::<u32, String>::new()).unwrap();
+    assert_eq!(empty, b"\x30\x00");
+}
+
+#[test]
+#[cfg(feature = "map")]
+fn test_err() {
+    use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+    use serde_asn1_der::{from_bytes, SerdeAsn1DerError::Asn1DerError as Error};
+    use std::collections::BTreeMap;
+
+    for der in [
+        // An entry with three elements
+        &b"\x30\x0a\x30\x08\x02\x01\x07\x02\x01\x04\x05\x00"[..],
+        // An entry that is not a sequence
+        b"\x30\x08\x31\x06\x02\x01\x07\x02\x01\x04",
+        // An entry with a single element
+        b"\x30\x05\x30\x03\x02\x01\x07",
+    ] {
+        match from_bytes::<BTreeMap<u8, u8>>(der) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            r => panic!("Invalid result: {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+
+/// X.509 `KeyUsage`-flags
+const DIGITAL_SIGNATURE: u16 = 1 << 0;
+const KEY_ENCIPHERMENT: u16 = 1 << 2;
+const KEY_CERT_SIGN: u16 = 1 << 5;
+const DECIPHER_ONLY: u16 = 1 << 8;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct KeyUsage(#[serde(with = "serde_asn1_der::named_bits")] u16);
+
+#[test]
+fn test() {
+    for (flags, der) in [
+        (DIGITAL_SIGNATURE | KEY_ENCIPHERMENT, &b"\x03\x02\x05\xa0"[..]),
+        (KEY_CERT_SIGN, b"\x03\x02\x02\x04"),
+        (DIGITAL_SIGNATURE | DECIPHER_ONLY, b"\x03\x03\x07\x80\x80"),
+        (0, b"\x03\x01\x00"),
+    ] {
+        assert_eq!(to_vec(&KeyUsage(flags)).unwrap(), der);
+        assert_eq!(from_bytes::<KeyUsage>(der).unwrap(), KeyUsage(flags));
+    }
+}
+
+#[test]
+fn test_err() {
+    // 17 named bits
+    match from_bytes::<KeyUsage>(b"\x03\x04\x07\x80\x00\x80") {
+        Err(serde_asn1_der::SerdeAsn1DerError::SerdeError(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[cfg(feature = "bitflags")]
+mod flags {
+    use serde_asn1_der::{from_bytes, to_vec};
+
+    bitflags::bitflags! {
+        /// X.509 `KeyUsage`-flags
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct KeyUsageFlags: u32 {
+            const DIGITAL_SIGNATURE = 1 << 0;
+            const KEY_ENCIPHERMENT = 1 << 2;
+            const KEY_CERT_SIGN = 1 << 5;
+            const DECIPHER_ONLY = 1 << 8;
+        }
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct KeyUsage(#[serde(with = "serde_asn1_der::named_bits::flags")] KeyUsageFlags);
+
+    #[test]
+    fn test() {
+        for (flags, der) in [
+            (KeyUsageFlags::DIGITAL_SIGNATURE | KeyUsageFlags::KEY_ENCIPHERMENT, &b"\x03\x02\x05\xa0"[..]),
+            (KeyUsageFlags::KEY_CERT_SIGN, b"\x03\x02\x02\x04"),
+            (KeyUsageFlags::DIGITAL_SIGNATURE | KeyUsageFlags::DECIPHER_ONLY, b"\x03\x03\x07\x80\x80"),
+            (KeyUsageFlags::empty(), b"\x03\x01\x00"),
+        ] {
+            assert_eq!(to_vec(&KeyUsage(flags)).unwrap(), der);
+            assert_eq!(from_bytes::<KeyUsage>(der).unwrap(), KeyUsage(flags));
+        }
+
+        // Wider than 16 bits and unknown bits are retained
+        let flags = KeyUsageFlags::from_bits_retain(1 << 20);
+        let der = to_vec(&KeyUsage(flags)).unwrap();
+        assert_eq!(der, b"\x03\x04\x03\x00\x00\x08");
+        assert_eq!(from_bytes::<KeyUsage>(&der).unwrap(), KeyUsage(flags));
+    }
+
+    #[test]
+    fn test_err() {
+        // 33 named bits
+        match from_bytes::<KeyUsage>(b"\x03\x06\x07\x00\x00\x00\x00\x80") {
+            Err(serde_asn1_der::SerdeAsn1DerError::SerdeError(_)) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{
+    from_bytes, to_vec, ObjectIdentifier,
+    SerdeAsn1DerError::{Asn1DerError as Error, UnexpectedTag},
+};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct AlgorithmIdentifier {
+    algorithm: ObjectIdentifier,
+    parameters: (),
+}
+
+#[test]
+fn test() {
+    // rsaEncryption (1.2.840.113549.1.1.1); the raw value is the representation used by `const_oid`
+    let raw = b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01";
+    let der = b"\x30\x0d\x06\x09\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01\x05\x00";
+
+    let oid = ObjectIdentifier::from_bytes(raw).unwrap();
+    assert_eq!(oid.as_bytes(), raw.as_ref());
+    assert_eq!(oid.arcs(), [1, 2, 840, 113549, 1, 1, 1]);
+    assert_eq!(oid.to_string(), "1.2.840.113549.1.1.1");
+
+    let plain = AlgorithmIdentifier { algorithm: oid, parameters: () };
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, der.as_ref());
+
+    let decoded: AlgorithmIdentifier = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+}
+
+#[test]
+fn test_arcs() {
+    let oid = ObjectIdentifier::from_arcs(&[1, 2, 840, 113549, 1, 1, 1]).unwrap();
+    assert_eq!(oid.as_bytes(), b"\x2a\x86\x48\x86\xf7\x0d\x01\x01\x01");
+
+    let parsed: ObjectIdentifier = "1.2.840.113549.1.1.1".parse().unwrap();
+    assert_eq!(parsed, oid);
+    assert!("1.2.".parse::<ObjectIdentifier>().is_err());
+    assert!("".parse::<ObjectIdentifier>().is_err());
+
+    // The second arc is unbounded below the `2`-arc
+    let oid = ObjectIdentifier::from_arcs(&[2, 999, 0]).unwrap();
+    assert_eq!(oid.as_bytes(), b"\x88\x37\x00");
+    assert_eq!(oid.arcs(), [2, 999, 0]);
+
+    // Out-of-range arcs
+    for arcs in [&[3, 1][..], &[1, 40], &[0, 40, 1], &[1]] {
+        match ObjectIdentifier::from_arcs(arcs) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
+
+#[test]
+fn test_err() {
+    // Non-minimal subidentifier
+    match ObjectIdentifier::from_bytes(b"\x2a\x80\x01") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Truncated subidentifier
+    match from_bytes::<ObjectIdentifier>(b"\x06\x02\x2a\x86") {
+        Err(_) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Invalid tag
+    match from_bytes::<ObjectIdentifier>(b"\x04\x01\x2a") {
+        Err(UnexpectedTag { expected: 0x06, found: 0x04 }) => (),
+        _ => panic!("Invalid result"),
+    }
+}
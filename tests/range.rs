@@ -0,0 +1,14 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+use std::ops::Range;
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct RangeStruct(#[serde(with = "serde_asn1_der::range")] Range<u32>);
+
+#[test]
+fn test() {
+    let der = b"\x30\x06\x02\x01\x05\x02\x01\x0a";
+    assert_eq!(to_vec(&RangeStruct(5..10)).unwrap(), der);
+    assert_eq!(from_bytes::<RangeStruct>(der).unwrap(), RangeStruct(5..10));
+}
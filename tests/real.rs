@@ -0,0 +1,51 @@
+use asn1_der::{
+    Asn1DerError,
+    Asn1DerErrorVariant::{InvalidData, Unsupported},
+};
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error};
+
+#[test]
+fn test() {
+    for (value, der) in [
+        (0.0, &b"\x09\x00"[..]),
+        (-0.0, b"\x09\x01\x43"),
+        (1.0, b"\x09\x03\x80\x00\x01"),
+        (-1.0, b"\x09\x03\xc0\x00\x01"),
+        (0.15625, b"\x09\x03\x80\xfb\x05"),
+        (f64::INFINITY, b"\x09\x01\x40"),
+        (f64::NEG_INFINITY, b"\x09\x01\x41"),
+        (f64::MAX, b"\x09\x0a\x81\x03\xcb\x1f\xff\xff\xff\xff\xff\xff"),
+        (f64::from_bits(1), b"\x09\x04\x81\xfb\xce\x01"),
+    ] {
+        assert_eq!(to_vec(&value).unwrap(), der);
+        let decoded: f64 = from_bytes(der).unwrap();
+        assert_eq!(decoded.to_bits(), value.to_bits());
+    }
+
+    assert_eq!(to_vec(&f64::NAN).unwrap(), b"\x09\x01\x42");
+    assert!(from_bytes::<f64>(b"\x09\x01\x42").unwrap().is_nan());
+    assert_eq!(to_vec(&0.15625f32).unwrap(), b"\x09\x03\x80\xfb\x05");
+    assert_eq!(from_bytes::<f32>(b"\x09\x03\x80\xfb\x05").unwrap(), 0.15625);
+}
+
+#[test]
+fn test_err() {
+    // Non-canonical encodings: an even mantissa, base 8, a non-minimal exponent
+    for der in [&b"\x09\x03\x80\x00\x02"[..], b"\x09\x03\x90\x00\x01", b"\x09\x04\x81\x00\x01\x01"] {
+        match from_bytes::<f64>(der) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+    // A decimal real, a value that is too large for `f64` and `f32`
+    for der in [&b"\x09\x04\x03\x31\x2e\x35"[..], b"\x09\x04\x81\x04\x00\x01"] {
+        match from_bytes::<f64>(der) {
+            Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+    match from_bytes::<f32>(b"\x09\x06\x80\x00\x01\x00\x00\x01") {
+        Err(Error(Asn1DerError { error: Unsupported(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
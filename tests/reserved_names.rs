@@ -0,0 +1,35 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+
+/// A user type that shares its name with a wrapper of other ASN.1 libraries
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct IntegerAsn1(Vec<u8>);
+
+/// A user type that shares its name with a `more_types`-wrapper
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct BitString(#[serde(with = "serde_bytes")] Vec<u8>);
+
+/// A user type that shares its name with a `more_types`-wrapper
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct SetOf(Vec<u8>);
+
+#[test]
+fn test() {
+    // User newtypes are transparent regardless of their name
+    let plain = IntegerAsn1(vec![7]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x03\x02\x01\x07");
+    assert_eq!(from_bytes::<IntegerAsn1>(&encoded).unwrap(), plain);
+
+    let plain = BitString(b"\x05\xa0".to_vec());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x04\x02\x05\xa0");
+    assert_eq!(from_bytes::<BitString>(&encoded).unwrap(), plain);
+
+    // An unsorted `Vec` stays an unsorted SEQUENCE
+    let plain = SetOf(vec![2, 1]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x06\x02\x01\x02\x02\x01\x01");
+    assert_eq!(from_bytes::<SetOf>(&encoded).unwrap(), plain);
+}
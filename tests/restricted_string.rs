@@ -0,0 +1,114 @@
+#![cfg(feature = "more_types")]
+
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{
+    from_bytes, to_vec, BmpString, Ia5String, NumericString, PrintableString,
+    SerdeAsn1DerError::{Asn1DerError as Error, UnexpectedTag},
+};
+
+#[test]
+fn test_numeric_string() {
+    let plain = NumericString::new("12 34").unwrap();
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x12\x05\x31\x32\x20\x33\x34");
+
+    let decoded: NumericString = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+    assert_eq!(decoded.as_str(), "12 34");
+}
+
+#[test]
+fn test_numeric_string_err() {
+    // `a` is not part of the character set
+    match to_vec(&NumericString("12a".to_string())) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    match NumericString::new("12a") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<NumericString>(b"\x12\x03\x31\x32\x61") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_printable_string() {
+    let plain = PrintableString::new("Hello").unwrap();
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x13\x05\x48\x65\x6c\x6c\x6f");
+
+    let decoded: PrintableString = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+    assert_eq!(decoded.as_str(), "Hello");
+}
+
+#[test]
+fn test_printable_string_err() {
+    // `!` is not part of the character set
+    match to_vec(&PrintableString("Hello!".to_string())) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    match PrintableString::new("Hello!") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<PrintableString>(b"\x13\x06\x48\x65\x6c\x6c\x6f\x21") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A UTF8String is not a PrintableString
+    match from_bytes::<PrintableString>(b"\x0c\x05\x48\x65\x6c\x6c\x6f") {
+        Err(UnexpectedTag { expected: 0x13, found: 0x0c }) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_ia5_string() {
+    let plain = Ia5String::new("user@example.com").unwrap();
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x16\x10\x75\x73\x65\x72\x40\x65\x78\x61\x6d\x70\x6c\x65\x2e\x63\x6f\x6d");
+
+    let decoded: Ia5String = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+}
+
+#[test]
+fn test_ia5_string_err() {
+    // `é` is not 7-bit ASCII
+    match to_vec(&Ia5String("jos\u{e9}@example.com".to_string())) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<Ia5String>(b"\x16\x05\x6a\x6f\x73\xc3\xa9") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_bmp_string() {
+    let plain = BmpString("AB".to_string());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x1e\x04\x00\x41\x00\x42");
+    assert_eq!(from_bytes::<BmpString>(&encoded).unwrap(), plain);
+
+    // A character outside of the BMP is encoded as surrogate pair
+    let plain = BmpString("\u{1f600}".to_string());
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x1e\x04\xd8\x3d\xde\x00");
+    assert_eq!(from_bytes::<BmpString>(&encoded).unwrap(), plain);
+}
+
+#[test]
+fn test_bmp_string_err() {
+    // An odd length, an unpaired surrogate and an invalid tag
+    for der in [&b"\x1e\x03\x00\x41\x00"[..], b"\x1e\x02\xd8\x3d", b"\x0c\x02\x41\x42"] {
+        assert!(from_bytes::<BmpString>(der).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "rfc3779")]
+
+use serde_asn1_der::{
+    from_bytes,
+    rfc3779::{Ipv4Prefix, Ipv4Range, Ipv6Prefix, Ipv6Range},
+    to_vec,
+};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn test_prefix() {
+    let prefix = Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 0), 8).unwrap();
+    let der = b"\x03\x02\x00\x0a";
+    assert_eq!(to_vec(&prefix).unwrap(), der);
+    assert_eq!(from_bytes::<Ipv4Prefix>(der).unwrap(), prefix);
+
+    // Prefixes that are not byte-aligned have unused bits
+    let prefix = Ipv4Prefix::new(Ipv4Addr::new(10, 64, 0, 0), 12).unwrap();
+    let der = b"\x03\x03\x04\x0a\x40";
+    assert_eq!(to_vec(&prefix).unwrap(), der);
+    assert_eq!(from_bytes::<Ipv4Prefix>(der).unwrap(), prefix);
+
+    // The default route is an empty BIT STRING
+    let prefix = Ipv4Prefix::new(Ipv4Addr::UNSPECIFIED, 0).unwrap();
+    assert_eq!(to_vec(&prefix).unwrap(), b"\x03\x01\x00");
+    assert_eq!(from_bytes::<Ipv4Prefix>(b"\x03\x01\x00").unwrap(), prefix);
+
+    let prefix = Ipv6Prefix::new("2001:db8::".parse().unwrap(), 32).unwrap();
+    let der = b"\x03\x05\x00\x20\x01\x0d\xb8";
+    assert_eq!(to_vec(&prefix).unwrap(), der);
+    assert_eq!(from_bytes::<Ipv6Prefix>(der).unwrap(), prefix);
+}
+
+#[test]
+fn test_range() {
+    // The lower bound is encoded without trailing zero bits, the upper bound without trailing one bits
+    let range = Ipv4Range::new(Ipv4Addr::new(10, 0, 0, 0), Ipv4Addr::new(10, 0, 0, 255)).unwrap();
+    let der = b"\x30\x0a\x03\x02\x01\x0a\x03\x04\x00\x0a\x00\x00";
+    assert_eq!(to_vec(&range).unwrap(), der);
+    assert_eq!(from_bytes::<Ipv4Range>(der).unwrap(), range);
+
+    let range = Ipv4Range::new(Ipv4Addr::new(10, 5, 0, 4), Ipv4Addr::new(10, 5, 0, 31)).unwrap();
+    let der = b"\x30\x0e\x03\x05\x02\x0a\x05\x00\x04\x03\x05\x05\x0a\x05\x00\x00";
+    assert_eq!(to_vec(&range).unwrap(), der);
+    assert_eq!(from_bytes::<Ipv4Range>(der).unwrap(), range);
+
+    let range = Ipv6Range::new("2001:db8::".parse().unwrap(), "2001:db8::ffff".parse().unwrap()).unwrap();
+    let decoded: Ipv6Range = from_bytes(&to_vec(&range).unwrap()).unwrap();
+    assert_eq!(decoded, range);
+    assert_eq!(decoded.max(), "2001:db8::ffff".parse::<Ipv6Addr>().unwrap());
+}
+
+#[test]
+fn test_err() {
+    // Invalid prefixes
+    assert!(Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 1), 8).is_err());
+    assert!(Ipv4Prefix::new(Ipv4Addr::new(10, 0, 0, 0), 33).is_err());
+    assert!(Ipv4Range::new(Ipv4Addr::new(10, 0, 0, 2), Ipv4Addr::new(10, 0, 0, 1)).is_err());
+
+    // A BIT STRING that is longer than an IPv4 address
+    assert!(from_bytes::<Ipv4Prefix>(b"\x03\x06\x00\x0a\x00\x00\x00\x00").is_err());
+    assert!(from_bytes::<Ipv6Prefix>(b"\x03\x06\x00\x0a\x00\x00\x00\x00").is_ok());
+
+    // A range with the lower bound above the upper bound
+    assert!(from_bytes::<Ipv4Range>(b"\x30\x08\x03\x02\x00\x0b\x03\x02\x00\x0a").is_err());
+}
@@ -4,7 +4,16 @@ use asn1_der::{
     Asn1DerError,
     Asn1DerErrorVariant::{InOutError, InvalidData},
 };
-use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error};
+use serde_asn1_der::{
+    encode_header, from_bufread, from_bytes, from_reader_iter, to_byte_buf_at, to_cursor, to_vec, to_vec_tagged,
+    to_vec_with_depth_limit, to_writer,
+    SerdeAsn1DerError::{Asn1DerError as Error, DepthLimitExceeded, UnexpectedTag},
+    VecBacking,
+};
+use std::{
+    collections::VecDeque,
+    io::{self, BufReader, Cursor, Read},
+};
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 struct TestStruct {
@@ -15,9 +24,90 @@ struct TestStruct {
     option: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct OptionalTestStruct {
+    a: u8,
+    b: Option<u8>,
+    c: u8,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct OptionalTailTestStruct {
+    number: u8,
+    option: Option<u8>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct OptionalSeqTestStruct {
+    number: u8,
+    list: Option<Vec<u8>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct SkippedOptionTestStruct {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u32>,
+    name: String,
+}
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct NameTestStruct {
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct BoxedTestStruct {
+    #[serde(with = "serde_bytes")]
+    data: Box<[u8]>,
+}
+
+/// A recursive type where each level is a sequence
+#[derive(Serialize, Deserialize)]
+struct Node {
+    child: Option<Box<Node>>,
+}
+impl Node {
+    /// Creates a chain of `depth` nested nodes
+    fn chain(depth: usize) -> Self {
+        let mut node = Node { child: None };
+        for _ in 1..depth {
+            node = Node { child: Some(Box::new(node)) };
+        }
+        node
+    }
+}
+/// Encodes `depth` nested empty sequences
+fn nested_sequences(depth: usize) -> Vec<u8> {
+    let mut headers = Vec::new();
+    let mut len = 0;
+    for _ in 0..depth {
+        let header = encode_header(0x30, len).unwrap();
+        len += header.len();
+        headers.push(header);
+    }
+    headers.into_iter().rev().flatten().collect()
+}
+impl Drop for Node {
+    fn drop(&mut self) {
+        // Unlink the chain iteratively to avoid a stack overflow
+        let mut next = self.child.take();
+        while let Some(mut node) = next {
+            next = node.child.take();
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 struct NewtypeTestStruct(TestStruct);
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct DefaultTestStruct {
+    number: u8,
+    #[serde(default)]
+    flag: bool,
+    #[serde(default)]
+    list: Vec<u8>,
+}
+
 #[test]
 fn test() {
     // Nested tuple
@@ -32,7 +122,7 @@ fn test() {
 
     // Test struct with `None`
     let plain = TestStruct { number: 7, vec: b"Testolope".to_vec(), tuple: (4, ()), option: None };
-    let der = b"\x30\x17\x02\x01\x07\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x30\x05\x02\x01\x04\x05\x00\x05\x00";
+    let der = b"\x30\x15\x02\x01\x07\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x30\x05\x02\x01\x04\x05\x00";
 
     let encoded = to_vec(&plain).unwrap();
     assert_eq!(encoded, der.as_ref());
@@ -67,12 +157,390 @@ fn test() {
     assert_eq!(decoded, plain);
 }
 
+#[test]
+fn test_writer() {
+    // A nested struct with a long-form length
+    let plain = (
+        TestStruct { number: 7, vec: vec![0x42; 300], tuple: (4, ()), option: Some("Testolope".to_string()) },
+        vec![(1usize, ()); 64],
+    );
+    let encoded = to_vec(&plain).unwrap();
+
+    // Stream the struct into a writer
+    let mut cursor = Cursor::new(Vec::new());
+    to_writer(&plain, &mut cursor).unwrap();
+    assert_eq!(cursor.into_inner(), encoded);
+}
+
+#[test]
+fn test_bufread() {
+    let plain = TestStruct { number: 7, vec: vec![0x42; 300], tuple: (4, ()), option: None };
+    let encoded = [to_vec(&plain).unwrap(), to_vec(&"Testolope").unwrap()].concat();
+
+    // Read both objects from the same reader
+    let mut reader = BufReader::with_capacity(64, Cursor::new(encoded));
+    let (mut first, mut second) = (Vec::new(), Vec::new());
+    let decoded: TestStruct = from_bufread(&mut reader, VecBacking(&mut first)).unwrap();
+    assert_eq!(decoded, plain);
+    let decoded: &str = from_bufread(&mut reader, VecBacking(&mut second)).unwrap();
+    assert_eq!(decoded, "Testolope");
+}
+
+/// A reader that generates a SEQUENCE OF `count` INTEGERs on the fly without holding the sequence in memory
+struct GeneratingReader {
+    count: usize,
+    pos: usize,
+}
+impl GeneratingReader {
+    /// The header of the sequence
+    const HEADER: [u8; 5] = [0x30, 0x83, 0, 0, 0];
+    /// An encoded element
+    const ELEMENT: [u8; 3] = [0x02, 0x01, 0x07];
+
+    /// The byte at `pos`
+    fn byte_at(&self, pos: usize) -> Option<u8> {
+        let len = self.count * Self::ELEMENT.len();
+        match pos {
+            0 | 1 => Some(Self::HEADER[pos]),
+            2..=4 => Some((len >> ((4 - pos) * 8)) as u8),
+            pos if pos < Self::HEADER.len() + len => Some(Self::ELEMENT[(pos - Self::HEADER.len()) % 3]),
+            _ => None,
+        }
+    }
+}
+impl Read for GeneratingReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut written = 0;
+        while let (Some(slot), Some(byte)) = (buf.get_mut(written), self.byte_at(self.pos)) {
+            *slot = byte;
+            self.pos += 1;
+            written += 1;
+        }
+        Ok(written)
+    }
+}
+
+#[test]
+fn test_reader_iter() {
+    // Elements are only read on demand
+    let mut reader = GeneratingReader { count: 1_000_000, pos: 0 };
+    let mut iter = from_reader_iter::<u8, _>(&mut reader).unwrap();
+    assert_eq!(iter.next().unwrap().unwrap(), 7);
+    drop(iter);
+    assert_eq!(reader.pos, 8);
+
+    // Stream a large sequence
+    let reader = BufReader::new(GeneratingReader { count: 1_000_000, pos: 0 });
+    let mut count = 0;
+    for element in from_reader_iter::<u8, _>(reader).unwrap() {
+        assert_eq!(element.unwrap(), 7);
+        count += 1;
+    }
+    assert_eq!(count, 1_000_000);
+
+    // A truncated sequence
+    let mut iter = from_reader_iter::<u8, _>(Cursor::new(b"\x30\x06\x02\x01\x07\x02")).unwrap();
+    assert_eq!(iter.next().unwrap().unwrap(), 7);
+    assert!(iter.next().unwrap().is_err());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_depth_limit() {
+    assert_eq!(to_vec(&Node::chain(64)).unwrap().len(), 128);
+    match to_vec(&Node::chain(65)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+    match to_vec(&Node::chain(10_000)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A custom limit
+    to_vec_with_depth_limit(&Node::chain(100), 100).unwrap();
+    match to_vec_with_depth_limit(&Node::chain(3), 2) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Deserialize nested sequences
+    from_bytes::<Node>(&nested_sequences(64)).unwrap();
+    match from_bytes::<Node>(&nested_sequences(65)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+    match from_bytes::<Node>(&nested_sequences(10_000)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_byte_buf_at() {
+    // Append two objects to an existing frame
+    let mut buf = b"\xff\xff".to_vec();
+    let first = to_byte_buf_at(&(7u8, ()), &mut buf).unwrap();
+    let second = to_byte_buf_at(&"Testolope", &mut buf).unwrap();
+    assert_eq!(first, 2..9);
+    assert_eq!(second, 9..20);
+
+    let decoded: (u8, ()) = from_bytes(&buf[first]).unwrap();
+    assert_eq!(decoded, (7, ()));
+    let decoded: String = from_bytes(&buf[second]).unwrap();
+    assert_eq!(decoded, "Testolope");
+}
+
+#[test]
+fn test_cursor() {
+    // Write two objects after the frame header
+    let mut cursor = Cursor::new(b"\xff\xff".to_vec());
+    cursor.set_position(2);
+    to_cursor(&(7u8, ()), &mut cursor).unwrap();
+    assert_eq!(cursor.position(), 9);
+    to_cursor(&"Testolope", &mut cursor).unwrap();
+    assert_eq!(cursor.position(), 20);
+    assert_eq!(cursor.into_inner(), b"\xff\xff\x30\x05\x02\x01\x07\x05\x00\x0c\x09Testolope");
+}
+
+#[test]
+fn test_encode_header() {
+    assert_eq!(encode_header(0x30, 300).unwrap(), b"\x30\x82\x01\x2c");
+    assert_eq!(encode_header(0x04, 127).unwrap(), b"\x04\x7f");
+    assert_eq!(encode_header(0x04, 128).unwrap(), b"\x04\x81\x80");
+
+    // A manually framed SEQUENCE
+    let value = [to_vec(&7u8).unwrap(), to_vec(&()).unwrap()].concat();
+    let der = [encode_header(0x30, value.len()).unwrap(), value].concat();
+    assert_eq!(from_bytes::<(u8, ())>(&der).unwrap(), (7, ()));
+
+    // Multi-byte tags
+    assert!(encode_header(0x1f, 1).is_err());
+}
+
+#[test]
+fn test_vec_tagged() {
+    let plain = TestStruct { number: 7, vec: b"Testolope".to_vec(), tuple: (4, ()), option: None };
+    let der = to_vec(&plain).unwrap();
+
+    // Write the struct as SET and as `[0]` without changing the contents
+    for tag in [0x31, 0xa0] {
+        let tagged = to_vec_tagged(&plain, tag).unwrap();
+        assert_eq!(tagged[0], tag);
+        assert_eq!(tagged[1..], der[1..]);
+    }
+    assert_eq!(to_vec_tagged(&7u8, 0x80).unwrap(), b"\x80\x01\x07");
+
+    // Tags that do not match the encoding and multi-byte tags
+    for tag in [0x80, 0x00, 0x3f] {
+        match to_vec_tagged(&plain, tag) {
+            Err(Error(Asn1DerError { .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+    assert!(to_vec_tagged(&7u8, 0xa0).is_err());
+}
+
+#[test]
+#[cfg(feature = "any")]
+fn test_sequence_with() {
+    use serde_asn1_der::sequence_with;
+
+    // Write the elements in reverse order
+    let (number, bytes, tuple) = (7u8, serde_bytes::Bytes::new(b"Testolope"), (4usize, ()));
+    let encoded = sequence_with(&[&tuple, &bytes, &number]).unwrap();
+    assert_eq!(
+        encoded,
+        b"\x30\x15\x30\x05\x02\x01\x04\x05\x00\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x02\x01\x07"
+    );
+}
+
+#[test]
+fn test_char() {
+    // A char outside of the basic multilingual plane that takes 4 UTF-8 bytes
+    let der = b"\x0c\x04\xf0\x9f\x98\x80";
+    assert_eq!(to_vec(&'😀').unwrap(), der);
+    assert_eq!(from_bytes::<char>(der).unwrap(), '😀');
+}
+
+#[derive(Deserialize, Debug, Eq, PartialEq)]
+struct Pair(u32, u32);
+
+#[test]
+fn test_tuple_struct_arity() {
+    assert_eq!(from_bytes::<Pair>(b"\x30\x06\x02\x01\x01\x02\x01\x02").unwrap(), Pair(1, 2));
+
+    // Excess elements are rejected even if trailing elements are allowed
+    let der = b"\x30\x09\x02\x01\x01\x02\x01\x02\x02\x01\x03";
+    match from_bytes::<Pair>(der) {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+    assert!(from_bytes::<(u32, u32)>(der).is_err());
+}
+
+#[test]
+fn test_default() {
+    // All fields present
+    let der = b"\x30\x0b\x02\x01\x07\x01\x01\xff\x30\x03\x02\x01\x04";
+    let decoded: DefaultTestStruct = from_bytes(der).unwrap();
+    assert_eq!(decoded, DefaultTestStruct { number: 7, flag: true, list: vec![4] });
+
+    // Missing trailing fields
+    let der = b"\x30\x03\x02\x01\x07";
+    let decoded: DefaultTestStruct = from_bytes(der).unwrap();
+    assert_eq!(decoded, DefaultTestStruct { number: 7, flag: false, list: Vec::new() });
+}
+
+#[test]
+fn test_optional() {
+    // An absent OPTIONAL contributes no bytes to the sequence
+    let plain = OptionalTailTestStruct { number: 7, option: None };
+    assert_eq!(to_vec(&plain).unwrap(), b"\x30\x03\x02\x01\x07");
+    assert_eq!(to_vec(&None::<u8>).unwrap(), b"");
+
+    // `None` cannot be read back in front of other elements or as element of a SEQUENCE OF or tuple
+    assert!(to_vec(&OptionalTestStruct { a: 7, b: None, c: 4 }).is_err());
+    assert!(to_vec(&vec![Some(1u8), None, Some(2)]).is_err());
+    assert!(to_vec(&(7u8, None::<u8>)).is_err());
+
+    // `Some` values that are encoded like `None`
+    assert!(to_vec(&Some(())).is_err());
+    assert!(to_vec(&Some(None::<u8>)).is_err());
+    assert_eq!(to_vec(&Some(((),))).unwrap(), b"\x30\x02\x05\x00");
+
+    // A present OPTIONAL is encoded inline
+    let plain = OptionalTestStruct { a: 7, b: Some(5), c: 4 };
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x09\x02\x01\x07\x02\x01\x05\x02\x01\x04");
+
+    let decoded: OptionalTestStruct = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+}
+
+#[test]
+fn test_optional_tail() {
+    // An absent OPTIONAL at the tail of a sequence
+    let decoded: OptionalTailTestStruct = from_bytes(b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(decoded, OptionalTailTestStruct { number: 7, option: None });
+    let decoded: OptionalTailTestStruct = from_bytes(b"\x30\x06\x02\x01\x07\x02\x01\x04").unwrap();
+    assert_eq!(decoded, OptionalTailTestStruct { number: 7, option: Some(4) });
+    let decoded: (u8, Option<u8>, Option<u8>) = from_bytes(b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(decoded, (7, None, None));
+
+    // An absent OPTIONAL in front of an element with another tag
+    let decoded: (Option<bool>, u8) = from_bytes(b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(decoded, (None, 7));
+    match from_bytes::<(Option<bool>, u8)>(b"\x30\x03\x0c\x01\x61") {
+        Err(UnexpectedTag { expected: 0x02, found: 0x0c }) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // Other missing fields are still rejected
+    assert!(from_bytes::<(u8, Option<u8>, u8)>(b"\x30\x03\x02\x01\x07").is_err());
+    assert!(from_bytes::<OptionalTestStruct>(b"\x30\x06\x02\x01\x07\x02\x01\x04").is_err());
+
+    // Variable-length sequences end with the last element
+    let decoded: Vec<Option<u8>> = from_bytes(b"\x30\x03\x02\x01\x07").unwrap();
+    assert_eq!(decoded, [Some(7)]);
+}
+
+#[test]
+fn test_optional_seq() {
+    // An absent SEQUENCE OF is distinct from an empty one
+    for (plain, der) in [
+        (OptionalSeqTestStruct { number: 7, list: None }, &b"\x30\x03\x02\x01\x07"[..]),
+        (OptionalSeqTestStruct { number: 7, list: Some(Vec::new()) }, b"\x30\x05\x02\x01\x07\x30\x00"),
+        (OptionalSeqTestStruct { number: 7, list: Some(vec![4]) }, b"\x30\x08\x02\x01\x07\x30\x03\x02\x01\x04"),
+    ] {
+        assert_eq!(to_vec(&plain).unwrap(), der);
+        assert_eq!(from_bytes::<OptionalSeqTestStruct>(der).unwrap(), plain);
+    }
+}
+
+#[test]
+fn test_skipped_option() {
+    let plain = SkippedOptionTestStruct { version: Some(7), name: "Testolope".to_string() };
+    let der = b"\x30\x0e\x02\x01\x07\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    assert_eq!(to_vec(&plain).unwrap(), der);
+    assert_eq!(from_bytes::<SkippedOptionTestStruct>(der).unwrap(), plain);
+
+    // The skipped field contributes zero bytes, so the present field is the first element of the SEQUENCE
+    let plain = SkippedOptionTestStruct { version: None, name: "Testolope".to_string() };
+    let der = b"\x30\x0b\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    assert_eq!(to_vec(&plain).unwrap(), der);
+    assert_eq!(from_bytes::<NameTestStruct>(der).unwrap(), NameTestStruct { name: "Testolope".to_string() });
+    assert_eq!(from_bytes::<SkippedOptionTestStruct>(der).unwrap(), plain);
+
+    let mut buf = Vec::new();
+    to_writer(&plain, &mut buf).unwrap();
+    assert_eq!(buf, der);
+}
+
+#[test]
+fn test_boxed_bytes() {
+    let plain = BoxedTestStruct { data: b"Testolope".to_vec().into_boxed_slice() };
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x0b\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65");
+
+    let decoded: BoxedTestStruct = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+}
+
+#[test]
+fn test_length_boundaries() {
+    // The length is encoded with the minimal amount of bytes at each byte-count transition
+    for (len, header) in [
+        (127, &b"\x04\x7f"[..]),
+        (128, b"\x04\x81\x80"),
+        (255, b"\x04\x81\xff"),
+        (256, b"\x04\x82\x01\x00"),
+        (65535, b"\x04\x82\xff\xff"),
+        (65536, b"\x04\x83\x01\x00\x00"),
+        (16777215, b"\x04\x83\xff\xff\xff"),
+        (16777216, b"\x04\x84\x01\x00\x00\x00"),
+    ] {
+        let bytes = vec![0x42; len];
+        let encoded = to_vec(&serde_bytes::Bytes::new(&bytes)).unwrap();
+        assert_eq!(&encoded[..header.len()], header);
+        assert_eq!(encoded.len(), header.len() + len);
+        assert_eq!(from_bytes::<&[u8]>(&encoded).unwrap().len(), len);
+
+        // The sequence length accounts for the length bytes of the element
+        let encoded = to_vec(&(serde_bytes::Bytes::new(&bytes),)).unwrap();
+        assert_eq!(&encoded[encoded.len() - len - header.len()..][..header.len()], header);
+    }
+}
+
+#[test]
+fn test_empty() {
+    let encoded = to_vec(&Vec::<u32>::new()).unwrap();
+    assert_eq!(encoded, b"\x30\x00");
+
+    let decoded: Vec<u32> = from_bytes(&encoded).unwrap();
+    assert!(decoded.is_empty());
+}
+
+#[test]
+fn test_vec_deque() {
+    // Other collections are encoded as SEQUENCE OF too
+    let mut plain: VecDeque<u8> = VecDeque::from(vec![4, 7]);
+    plain.push_front(1);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x30\x09\x02\x01\x01\x02\x01\x04\x02\x01\x07");
+    assert_eq!(from_bytes::<VecDeque<u8>>(&encoded).unwrap(), plain);
+
+    let encoded = to_vec(&VecDeque::<u8>::new()).unwrap();
+    assert_eq!(encoded, b"\x30\x00");
+    assert!(from_bytes::<VecDeque<u8>>(&encoded).unwrap().is_empty());
+}
+
 #[test]
 fn test_err() {
     // Invalid tag
     let der = b"\x31\x15\x02\x01\x07\x04\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x30\x05\x02\x01\x04\x05\x00";
     match from_bytes::<TestStruct>(der) {
-        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        Err(UnexpectedTag { expected: 0x30, found: 0x31 }) => (),
         _ => panic!("Invalid result"),
     }
 
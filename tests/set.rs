@@ -0,0 +1,78 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{
+    from_bytes, to_vec,
+    SerdeAsn1DerError::{Asn1DerError as Error, SerdeError, UnexpectedTag},
+    Set,
+};
+
+/// A struct whose field order differs from the canonical order
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Unsorted {
+    name: String,
+    number: u8,
+}
+
+/// The same struct with the fields in canonical order
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Sorted {
+    number: u8,
+    name: String,
+}
+
+#[test]
+fn test() {
+    // The fields are sorted by their encoding
+    let der = b"\x31\x0e\x02\x01\x07\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let unsorted = Set(Unsorted { name: "Testolope".to_string(), number: 7 });
+    assert_eq!(to_vec(&unsorted).unwrap(), der.as_ref());
+    assert_eq!(from_bytes::<Set<Unsorted>>(der).unwrap(), unsorted);
+    assert_eq!(from_bytes::<Set<(String, u8)>>(der).unwrap(), Set(("Testolope".to_string(), 7)));
+
+    let plain = Set(Sorted { number: 7, name: "Testolope".to_string() });
+    assert_eq!(to_vec(&plain).unwrap(), der.as_ref());
+    assert_eq!(from_bytes::<Set<Sorted>>(der).unwrap(), plain);
+}
+
+/// A struct with an optional field
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct WithOptional {
+    name: String,
+    flag: Option<bool>,
+    number: u8,
+}
+
+#[test]
+fn test_optional() {
+    // An absent field is read as `None`
+    let der = b"\x31\x0e\x02\x01\x07\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let decoded: Set<WithOptional> = from_bytes(der).unwrap();
+    assert_eq!(decoded, Set(WithOptional { name: "Testolope".to_string(), flag: None, number: 7 }));
+
+    let value = Set(WithOptional { name: "Testolope".to_string(), flag: Some(true), number: 7 });
+    assert_eq!(from_bytes::<Set<WithOptional>>(&to_vec(&value).unwrap()).unwrap(), value);
+}
+
+#[test]
+fn test_err() {
+    // Unsorted elements
+    match from_bytes::<Set<(String, u8)>>(b"\x31\x0e\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65\x02\x01\x07") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // An element that no field expects
+    match from_bytes::<Set<(String, u8)>>(b"\x31\x06\x01\x01\xff\x02\x01\x07") {
+        Err(SerdeError(_)) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A sequence is not a set
+    match from_bytes::<Set<Sorted>>(b"\x30\x0e\x02\x01\x07\x0c\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65") {
+        Err(UnexpectedTag { expected: 0x31, found: 0x30 }) => (),
+        _ => panic!("Invalid result"),
+    }
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "more_types")]
+
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde::{Serialize, Serializer};
+use serde_asn1_der::{
+    from_bytes, to_vec, Implicit, PrintableString,
+    SerdeAsn1DerError::{Asn1DerError as Error, UnexpectedTag},
+    SetOf, SetOfRaw,
+};
+use std::cell::Cell;
+
+/// A value that counts how often it is serialized
+struct Counted<'a>(u8, &'a Cell<usize>);
+impl<'a> Serialize for Counted<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.1.set(self.1.get() + 1);
+        self.0.serialize(serializer)
+    }
+}
+
+#[test]
+fn test() {
+    // Elements are sorted by their encoding
+    let plain = SetOf(vec![256u32, 7, 1]);
+    let der = b"\x31\x0a\x02\x01\x01\x02\x01\x07\x02\x02\x01\x00";
+
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, der.as_ref());
+
+    let decoded: SetOf<u32> = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded.0, [1, 7, 256]);
+}
+
+#[test]
+fn test_nested() {
+    // Wrappers are streamed, so the value is serialized once per pass regardless of the nesting
+    let count = Cell::new(0);
+    let inner = SetOf(vec![Implicit::<1, _>(Counted(7, &count)), Implicit(Counted(4, &count))]);
+    let plain = SetOf(vec![Implicit::<0, _>(SetOf(vec![inner])), Implicit(SetOf(Vec::new()))]);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x31\x0c\xa0\x00\xa0\x08\x31\x06\x81\x01\x04\x81\x01\x07");
+    assert_eq!(count.get(), 4);
+
+    // A restricted string within an implicit tag
+    let plain = Implicit::<2, _>(PrintableString::new("Test").unwrap());
+    assert_eq!(to_vec(&plain).unwrap(), b"\x82\x04Test");
+}
+
+#[test]
+fn test_empty() {
+    let encoded = to_vec(&SetOf(Vec::<u32>::new())).unwrap();
+    assert_eq!(encoded, b"\x31\x00");
+    let decoded: SetOf<u32> = from_bytes(&encoded).unwrap();
+    assert!(decoded.is_empty());
+}
+
+#[test]
+fn test_err() {
+    // Unsorted elements
+    match from_bytes::<SetOf<u32>>(b"\x31\x06\x02\x01\x07\x02\x01\x01") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // A sequence is not a set
+    match from_bytes::<SetOf<u32>>(b"\x30\x03\x02\x01\x01") {
+        Err(UnexpectedTag { expected: 0x31, found: 0x30 }) => (),
+        _ => panic!("Invalid result"),
+    }
+}
+
+#[test]
+fn test_raw() {
+    // Elements keep their insertion order
+    let plain = SetOfRaw(vec![256u32, 7, 1]);
+    let der = b"\x31\x0a\x02\x02\x01\x00\x02\x01\x07\x02\x01\x01";
+
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, der.as_ref());
+
+    let decoded: SetOfRaw<u32> = from_bytes(&encoded).unwrap();
+    assert_eq!(decoded, plain);
+
+    // Presorted elements are canonical
+    let encoded = to_vec(&SetOfRaw(vec![1u32, 7, 256])).unwrap();
+    assert_eq!(encoded, to_vec(&SetOf(vec![256u32, 7, 1])).unwrap());
+}
@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate serde_derive;
+use serde_asn1_der::{from_bytes, to_vec};
+use std::{rc::Rc, sync::Arc};
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct Shared {
+    #[serde(with = "serde_asn1_der::shared_bytes")]
+    arc: Arc<[u8]>,
+    #[serde(with = "serde_asn1_der::shared_bytes")]
+    rc: Rc<[u8]>,
+}
+
+#[test]
+fn test() {
+    let bytes: Vec<u8> = (0..4096).map(|i| i as u8).collect();
+    let shared = Shared { arc: Arc::from(bytes.as_slice()), rc: Rc::from(&b"Testolope"[..]) };
+
+    let der = to_vec(&shared).unwrap();
+    assert_eq!(der[..8], *b"\x30\x82\x10\x0f\x04\x82\x10\x00");
+    assert_eq!(der[8..4104], *bytes);
+    assert_eq!(from_bytes::<Shared>(&der).unwrap(), shared);
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "more_types")]
+
+#[macro_use]
+extern crate serde_derive;
+use serde::Deserialize;
+use serde_asn1_der::{
+    encode_header, from_bytes, to_vec, BitString, Deserializer, Implicit, ObjectIdentifier,
+    SerdeAsn1DerError::{DepthLimitExceeded, UnexpectedTag},
+    Spanned,
+};
+
+/// A simplified certificate
+#[derive(Serialize, Deserialize)]
+struct Certificate<'a> {
+    #[serde(borrow)]
+    tbs_certificate: Spanned<'a, TbsCertificate>,
+    signature_algorithm: (ObjectIdentifier,),
+    signature: BitString,
+}
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TbsCertificate {
+    serial_number: u64,
+    signature: (ObjectIdentifier,),
+    subject: String,
+    subject_public_key: BitString,
+}
+
+#[test]
+fn test() {
+    let ecdsa_with_sha256 = ObjectIdentifier::from_bytes(b"\x2a\x86\x48\xce\x3d\x04\x03\x02").unwrap();
+    let tbs_certificate = TbsCertificate {
+        serial_number: 4,
+        signature: (ecdsa_with_sha256.clone(),),
+        subject: "Testolope".to_string(),
+        subject_public_key: BitString::with_bytes(&[0x04; 65], 0).unwrap(),
+    };
+    let tbs_der = to_vec(&tbs_certificate).unwrap();
+    let signature = BitString::with_bytes(&[0x30; 70], 0).unwrap();
+    let der = to_vec(&(&tbs_certificate, (&ecdsa_with_sha256,), &signature)).unwrap();
+
+    // Parse the certificate and capture the signed region
+    let certificate: Certificate = from_bytes(&der).unwrap();
+    assert_eq!(certificate.tbs_certificate.value, tbs_certificate);
+    assert_eq!(certificate.tbs_certificate.raw, tbs_der);
+    assert_eq!(certificate.tbs_certificate.raw.as_ptr(), der[3..].as_ptr());
+    assert_eq!(certificate.tbs_certificate.subject, "Testolope");
+
+    // Only the value is serialized
+    assert_eq!(to_vec(&certificate).unwrap(), der);
+}
+
+/// A recursive type that captures each level
+#[derive(Deserialize)]
+struct Nested<'a> {
+    #[serde(borrow)]
+    next: Option<Box<Spanned<'a, Nested<'a>>>>,
+}
+
+#[test]
+fn test_context() {
+    // The IMPLICIT tag and the options apply to the value
+    let der = b"\x81\x01\x07";
+    let spanned: Implicit<1, Spanned<u8>> = from_bytes(der).unwrap();
+    assert_eq!((spanned.0.value, spanned.0.raw), (7, &der[..]));
+
+    let der = b"\x13\x09\x54\x65\x73\x74\x6f\x6c\x6f\x70\x65";
+    let mut deserializer = Deserializer::from_bytes(der).unwrap();
+    deserializer.set_accept_any_string_tag(true);
+    let spanned: Spanned<String> = Spanned::deserialize(&mut deserializer).unwrap();
+    assert_eq!((spanned.value.as_str(), spanned.raw), ("Testolope", &der[..]));
+
+    // Errors are passed as they are
+    match from_bytes::<Spanned<u8>>(b"\x01\x01\xff") {
+        Err(UnexpectedTag { expected: 0x02, found: 0x01 }) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    // The nesting depth is not reset
+    let nested: Nested = from_bytes(b"\x30\x02\x30\x00").unwrap();
+    assert_eq!(nested.next.unwrap().raw, b"\x30\x00");
+    let mut der = Vec::new();
+    for _ in 0..10_000 {
+        let header = encode_header(0x30, der.len()).unwrap();
+        der.splice(0..0, header);
+    }
+    match from_bytes::<Nested>(&der) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+}
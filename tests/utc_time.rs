@@ -0,0 +1,90 @@
+#![cfg(feature = "more_types")]
+
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{from_bytes, to_vec, SerdeAsn1DerError::Asn1DerError as Error, UtcTime, UtcTimeWindow};
+
+#[test]
+fn test() {
+    let plain = UtcTime(1_576_465_330);
+    let encoded = to_vec(&plain).unwrap();
+    assert_eq!(encoded, b"\x17\x0d191216030210Z");
+    assert_eq!(from_bytes::<UtcTime>(&encoded).unwrap(), plain);
+}
+
+#[test]
+fn test_century() {
+    // The boundaries of the RFC 5280 window
+    for (timestamp, der) in [
+        (-631_152_000, &b"\x17\x0d500101000000Z"[..]),
+        (946_598_400, b"\x17\x0d991231000000Z"),
+        (946_684_800, b"\x17\x0d000101000000Z"),
+        (2_524_521_600, b"\x17\x0d491231000000Z"),
+    ] {
+        assert_eq!(to_vec(&UtcTime(timestamp)).unwrap(), der);
+        assert_eq!(from_bytes::<UtcTime>(der).unwrap(), UtcTime(timestamp));
+    }
+
+    // 1949-12-31T23:59:59Z and 2050-01-01T00:00:00Z are outside of the window
+    assert!(to_vec(&UtcTime(-631_152_001)).is_err());
+    assert!(to_vec(&UtcTime(2_524_608_000)).is_err());
+}
+
+#[test]
+fn test_window() {
+    // YY=49 and YY=50 under the default window (1950 to 2049) and a custom window (1900 to 1999)
+    let (yy49, yy50) = (b"\x17\x0d491231000000Z", b"\x17\x0d500101000000Z");
+    assert_eq!(from_bytes::<UtcTime>(yy49).unwrap(), UtcTime(2_524_521_600));
+    assert_eq!(from_bytes::<UtcTime>(yy50).unwrap(), UtcTime(-631_152_000));
+    assert_eq!(from_bytes::<UtcTimeWindow<1950>>(yy49).unwrap(), UtcTimeWindow(2_524_521_600));
+    assert_eq!(from_bytes::<UtcTimeWindow<1900>>(yy49).unwrap(), UtcTimeWindow(-631_238_400));
+    assert_eq!(from_bytes::<UtcTimeWindow<1900>>(yy50).unwrap(), UtcTimeWindow(-631_152_000));
+
+    // A window that starts in the middle of a century
+    assert_eq!(from_bytes::<UtcTimeWindow<2000>>(yy49).unwrap(), UtcTimeWindow(2_524_521_600));
+    assert_eq!(from_bytes::<UtcTimeWindow<2000>>(yy50).unwrap(), UtcTimeWindow(2_524_608_000));
+    assert_eq!(UtcTime::from_bytes_in_window(b"491231000000Z", 1900).unwrap(), UtcTime(-631_238_400));
+
+    // Only years within the window can be encoded
+    assert_eq!(to_vec(&UtcTimeWindow::<1900>(-631_238_400)).unwrap(), yy49);
+    assert!(to_vec(&UtcTimeWindow::<1900>(2_524_521_600)).is_err());
+    assert!(to_vec(&UtcTimeWindow::<2000>(-631_152_000)).is_err());
+}
+
+#[test]
+fn test_lenient() {
+    for (bytes, timestamp) in [
+        // Without seconds
+        (&b"1912160302Z"[..], 1_576_465_320),
+        // With an offset from UTC
+        (b"191215220210-0500", 1_576_465_330),
+        (b"191216040210+0100", 1_576_465_330),
+        (b"1912152202-0500", 1_576_465_320),
+    ] {
+        assert_eq!(UtcTime::from_bytes(bytes).unwrap(), UtcTime(timestamp));
+    }
+
+    // The lenient forms are re-encoded in DER form
+    let decoded: UtcTime = from_bytes(b"\x17\x0b1912160302Z").unwrap();
+    assert_eq!(to_vec(&decoded).unwrap(), b"\x17\x0d191216030200Z");
+}
+
+#[test]
+fn test_err() {
+    // Invalid offsets, lengths and time zones
+    for bytes in [
+        &b"1912160302+2400"[..],
+        b"1912160302+0060",
+        b"1912160302+01",
+        b"191216030Z",
+        b"19121603021Z",
+        b"19121603021000Z",
+        b"191216030210",
+        b"1912160302x0500",
+        b"191316030210Z",
+    ] {
+        match UtcTime::from_bytes(bytes) {
+            Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+            _ => panic!("Invalid result"),
+        }
+    }
+}
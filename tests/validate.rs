@@ -0,0 +1,115 @@
+#[macro_use]
+extern crate serde_derive;
+use asn1_der::{Asn1DerError, Asn1DerErrorVariant::InvalidData};
+use serde_asn1_der::{
+    to_byte_buf_at, to_vec, validate_der, EmptySeq,
+    SerdeAsn1DerError::{Asn1DerError as Error, DepthLimitExceeded, TrailingData},
+};
+
+/// A choice between a string and an integer
+#[derive(Serialize)]
+enum Choice {
+    String(String),
+    Number(u64),
+}
+
+/// A struct that contains all supported types
+#[derive(Serialize)]
+struct Everything {
+    bool: bool,
+    unsigned: (u8, u16, u32, u64, u128, usize),
+    signed: (i8, i16, i32, i64, i128, isize),
+    real: (f32, f64),
+    null: (),
+    empty_seq: EmptySeq,
+    option: (Option<u8>, Option<u8>),
+    #[serde(with = "serde_bytes")]
+    bytes: Vec<u8>,
+    string: String,
+    choice: Vec<Choice>,
+    nested: Vec<Vec<u8>>,
+}
+
+/// Creates `depth` nested sequences
+fn nested(depth: usize) -> Vec<u8> {
+    // Compute the headers from the inside out
+    let (mut headers, mut len) = (Vec::new(), 0usize);
+    for _ in 0..depth {
+        let header = match len {
+            0..=0x7f => vec![0x30, len as u8],
+            0x80..=0xff => vec![0x30, 0x81, len as u8],
+            _ => vec![0x30, 0x82, (len >> 8) as u8, len as u8],
+        };
+        len += header.len();
+        headers.push(header);
+    }
+    headers.into_iter().rev().flatten().collect()
+}
+
+#[test]
+fn test() {
+    validate_der(b"\x30\x0c\x02\x01\x07\x30\x05\x01\x01\xff\x05\x00\x04\x00").unwrap();
+    validate_der(&nested(64)).unwrap();
+}
+
+#[test]
+fn test_self_check() {
+    // The serializer validates its output in debug builds and would panic on invalid DER
+    let plain = Everything {
+        bool: true,
+        unsigned: (0, 0x80, u32::MAX, u64::MAX, u128::MAX, 7),
+        signed: (-1, i16::MIN, -0x80, i64::MAX, i128::MIN, -7),
+        real: (0.5, -1e300),
+        null: (),
+        empty_seq: EmptySeq,
+        option: (Some(3), Some(4)),
+        bytes: vec![0x42; 300],
+        string: "Testolope".to_string(),
+        choice: vec![Choice::String("a".to_string()), Choice::Number(7)],
+        nested: vec![Vec::new(), vec![1, 2]],
+    };
+    let encoded = to_vec(&plain).unwrap();
+    validate_der(&encoded).unwrap();
+
+    let mut buf = Vec::new();
+    to_byte_buf_at(&plain, &mut buf).unwrap();
+    assert_eq!(buf, encoded);
+}
+
+#[cfg(feature = "more_types")]
+#[test]
+fn test_self_check_more_types() {
+    use serde_asn1_der::{BitString, GeneralizedTime, ObjectIdentifier, PrintableString, SetOf, SetOfRaw, UtcTime};
+
+    let plain = (
+        BitString::with_bits(&[true, false, true]),
+        ObjectIdentifier::from_arcs(&[1, 2, 840, 113549]).unwrap(),
+        PrintableString::new("Hello").unwrap(),
+        (UtcTime(0), GeneralizedTime(0)),
+        SetOf::from(vec![7u8, 4]),
+        SetOfRaw::from(vec![7u8, 4]),
+    );
+    validate_der(&to_vec(&plain).unwrap()).unwrap();
+}
+
+#[test]
+fn test_err() {
+    match validate_der(&nested(10_000)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+    match validate_der(&nested(65)) {
+        Err(DepthLimitExceeded) => (),
+        _ => panic!("Invalid result"),
+    }
+
+    match validate_der(b"\x05\x00\x05\x00") {
+        Err(TrailingData) => (),
+        _ => panic!("Invalid result"),
+    }
+    // A subobject with a non-minimal length
+    match validate_der(b"\x30\x04\x02\x81\x01\x07") {
+        Err(Error(Asn1DerError { error: InvalidData(_), .. })) => (),
+        _ => panic!("Invalid result"),
+    }
+}